@@ -0,0 +1,215 @@
+// Command line options of the benchmark
+
+use crate::keys::Distribution;
+use crate::report::Format;
+
+pub const USAGE: &str = "\
+Usage: cargo run --release --example bench -- [OPTIONS]
+
+Options:
+    --size N          keys in the tree before the operations start [1000000]
+    --ops N           operations to run [size / 5]
+    --mix I,F,R       percentages of inserts, finds and removes [34,33,33]
+    --keys DIST       uniform, sequential, reverse, zipf[:EXPONENT] or
+                      clustered[:CLUSTERS] [uniform]
+    --seed N          seed of the random number generator [random]
+    --policy LIST     comma separated wavl, avl, red-black, unbalanced or all [all]
+    --format FORMAT   table, json or csv [table]
+    --record FILE     write the generated workload to FILE before running it
+    --replay FILE     run the workload recorded in FILE instead of generating one
+    --help            print this help
+
+Inserts and finds draw their keys from DIST, removes pick one of the keys the
+tree started with. The seed is printed to stderr, running again with it
+generates the same workload.";
+
+#[derive(Clone, Copy)]
+pub enum Policy {
+    Wavl,
+    Avl,
+    RedBlack,
+    Unbalanced,
+}
+
+impl Policy {
+    const ALL: [Policy; 4] = [
+        Policy::Wavl,
+        Policy::Avl,
+        Policy::RedBlack,
+        Policy::Unbalanced,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Policy::Wavl => "wavl",
+            Policy::Avl => "avl",
+            Policy::RedBlack => "red-black",
+            Policy::Unbalanced => "unbalanced",
+        }
+    }
+}
+
+// Percentages of inserts and finds, removes take the rest
+pub struct Mix {
+    pub insert: u32,
+    pub find: u32,
+}
+
+pub struct Args {
+    pub size: usize,
+    pub ops: usize,
+    // `size` plus `ops`, the most keys the workload can touch
+    pub workload: usize,
+    pub mix: Mix,
+    pub keys: Distribution,
+    pub seed: Option<u64>,
+    pub policies: Vec<Policy>,
+    pub format: Format,
+    pub record: Option<String>,
+    pub replay: Option<String>,
+}
+
+impl Args {
+    // Prints the usage and exits on `--help`
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Args, String> {
+        let mut args = args.into_iter();
+        let mut size: usize = 1_000_000;
+        let mut ops = None;
+        let mut mix = Mix {
+            insert: 34,
+            find: 33,
+        };
+        let mut keys = Distribution::Uniform;
+        let mut seed = None;
+        let mut policies = Policy::ALL.to_vec();
+        let mut format = Format::Table;
+        let mut record = None;
+        let mut replay = None;
+
+        while let Some(flag) = args.next() {
+            match flag.as_str() {
+                "--size" => size = number(&flag, &value(&mut args, &flag)?)?,
+                "--ops" => ops = Some(number(&flag, &value(&mut args, &flag)?)?),
+                "--mix" => mix = parse_mix(&value(&mut args, &flag)?)?,
+                "--keys" => keys = parse_distribution(&value(&mut args, &flag)?)?,
+                "--seed" => seed = Some(number(&flag, &value(&mut args, &flag)?)?),
+                "--policy" => policies = parse_policies(&value(&mut args, &flag)?)?,
+                "--format" => format = parse_format(&value(&mut args, &flag)?)?,
+                "--record" => record = Some(value(&mut args, &flag)?),
+                "--replay" => replay = Some(value(&mut args, &flag)?),
+                "--help" | "-h" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ => return Err(format!("Unknown option `{}`", flag)),
+            }
+        }
+
+        if size == 0 {
+            return Err("--size must be at least 1".to_string());
+        }
+        let ops = ops.unwrap_or(size / 5);
+        let workload = size
+            .checked_add(ops)
+            .ok_or_else(|| format!("--size {} plus --ops {} is too large", size, ops))?;
+        let support = keys.support(workload);
+        if size as u64 > support {
+            return Err(format!(
+                "--size {} is above the {} distinct keys of the key distribution",
+                size, support
+            ));
+        }
+        if record.is_some() && replay.is_some() {
+            return Err("--record and --replay cannot be combined".to_string());
+        }
+        Ok(Args {
+            size,
+            ops,
+            workload,
+            mix,
+            keys,
+            seed,
+            policies,
+            format,
+            record,
+            replay,
+        })
+    }
+}
+
+fn value(args: &mut impl Iterator<Item = String>, flag: &str) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn number<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, not `{}`", flag, value))
+}
+
+fn parse_mix(value: &str) -> Result<Mix, String> {
+    let parts = value
+        .split(',')
+        .map(|part| number("--mix", part))
+        .collect::<Result<Vec<u32>, _>>()?;
+    let total: u64 = parts.iter().map(|&part| u64::from(part)).sum();
+    match parts[..] {
+        [insert, find, _] if total == 100 => Ok(Mix { insert, find }),
+        _ => Err(format!(
+            "--mix expects three percentages adding up to 100, not `{}`",
+            value
+        )),
+    }
+}
+
+fn parse_distribution(value: &str) -> Result<Distribution, String> {
+    let (name, parameter) = match value.split_once(':') {
+        Some((name, parameter)) => (name, Some(parameter)),
+        None => (value, None),
+    };
+    let distribution = match (name, parameter) {
+        ("uniform", None) => Distribution::Uniform,
+        ("sequential", None) => Distribution::Sequential,
+        ("reverse", None) => Distribution::Reverse,
+        ("zipf", exponent) => {
+            let exponent = exponent.map_or(Ok(1.0), |e| number("--keys zipf", e))?;
+            if exponent <= 0.0 {
+                return Err("The Zipf exponent must be positive".to_string());
+            }
+            Distribution::Zipf(exponent)
+        }
+        ("clustered", clusters) => {
+            let clusters = clusters.map_or(Ok(16), |c| number("--keys clustered", c))?;
+            if clusters == 0 {
+                return Err("There must be at least one cluster".to_string());
+            }
+            Distribution::Clustered(clusters)
+        }
+        _ => return Err(format!("Unknown key distribution `{}`", value)),
+    };
+    Ok(distribution)
+}
+
+fn parse_policies(value: &str) -> Result<Vec<Policy>, String> {
+    if value == "all" {
+        return Ok(Policy::ALL.to_vec());
+    }
+    value
+        .split(',')
+        .map(|name| {
+            Policy::ALL
+                .into_iter()
+                .find(|policy| policy.name() == name)
+                .ok_or_else(|| format!("Unknown balancing policy `{}`", name))
+        })
+        .collect()
+}
+
+fn parse_format(value: &str) -> Result<Format, String> {
+    match value {
+        "table" => Ok(Format::Table),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        _ => Err(format!("Unknown output format `{}`", value)),
+    }
+}
@@ -0,0 +1,148 @@
+// Key distributions of the benchmark workload
+
+use std::collections::HashSet;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+#[derive(Clone, Copy)]
+pub enum Distribution {
+    Uniform,
+    // Counts up from 0
+    Sequential,
+    // Counts down from 0
+    Reverse,
+    // Roughly Zipf distributed ranks with the given exponent, scattered over
+    // the key space so the popular keys are not neighbours
+    Zipf(f64),
+    // Uniform offsets around the given number of random centers
+    Clustered(u32),
+}
+
+impl Distribution {
+    // How many different keys the distribution can produce for a workload of
+    // `size` keys
+    pub fn support(self, size: usize) -> u64 {
+        match self {
+            Distribution::Uniform | Distribution::Sequential | Distribution::Reverse => 1 << 32,
+            Distribution::Zipf(_) => ZIPF_RANKS as u64,
+            Distribution::Clustered(clusters) => {
+                u64::from(clusters) * u64::from(spread(clusters, size))
+            }
+        }
+    }
+}
+
+pub struct Keys {
+    distribution: Distribution,
+    counter: i32,
+    centers: Vec<i32>,
+    spread: u32,
+}
+
+impl Keys {
+    // `size` is the number of distinct keys the workload needs at least
+    pub fn new(distribution: Distribution, size: usize, rng: &mut StdRng) -> Keys {
+        let (centers, spread) = match distribution {
+            Distribution::Clustered(clusters) => {
+                let centers = (0..clusters).map(|_| rng.gen()).collect();
+                (centers, spread(clusters, size))
+            }
+            _ => (Vec::new(), 0),
+        };
+        Keys {
+            distribution,
+            counter: 0,
+            centers,
+            spread,
+        }
+    }
+
+    pub fn next(&mut self, rng: &mut StdRng) -> i32 {
+        match self.distribution {
+            Distribution::Uniform => rng.gen(),
+            Distribution::Sequential => {
+                let key = self.counter;
+                self.counter = key.wrapping_add(1);
+                key
+            }
+            Distribution::Reverse => {
+                let key = self.counter;
+                self.counter = key.wrapping_sub(1);
+                key
+            }
+            Distribution::Zipf(exponent) => zipf_key(zipf_rank(rng, exponent, ZIPF_RANKS as f64)),
+            Distribution::Clustered(_) => {
+                let center = self.centers[rng.gen_range(0..self.centers.len())];
+                center.wrapping_add(rng.gen_range(0..self.spread) as i32)
+            }
+        }
+    }
+
+    // `count` different keys for the initial tree. Sequential keys and the
+    // most popular Zipf ranks need no drawing, random keys are drawn until
+    // enough are distinct, within a bounded number of attempts.
+    pub fn distinct(&mut self, count: usize, rng: &mut StdRng) -> Result<Vec<i32>, String> {
+        match self.distribution {
+            Distribution::Sequential | Distribution::Reverse => {
+                Ok((0..count).map(|_| self.next(rng)).collect())
+            }
+            Distribution::Zipf(_) => {
+                let mut keys: Vec<i32> = (1..=count as u32).map(zipf_key).collect();
+                keys.shuffle(rng);
+                Ok(keys)
+            }
+            Distribution::Uniform | Distribution::Clustered(_) => {
+                let attempts = count.saturating_mul(MAX_ATTEMPTS_PER_KEY);
+                let mut seen = HashSet::with_capacity(count);
+                let mut keys = Vec::with_capacity(count);
+                for _ in 0..attempts {
+                    let key = self.next(rng);
+                    if seen.insert(key) {
+                        keys.push(key);
+                        if keys.len() == count {
+                            return Ok(keys);
+                        }
+                    }
+                }
+                Err(format!(
+                    "only {} distinct keys out of {} attempts, --size {} is too large for --keys",
+                    keys.len(),
+                    attempts,
+                    count
+                ))
+            }
+        }
+    }
+}
+
+// Zipf ranks go up to the largest i32
+const ZIPF_RANKS: u32 = i32::MAX as u32;
+
+// Random draws per wanted key before the fill gives up
+const MAX_ATTEMPTS_PER_KEY: usize = 20;
+
+// Leaves room for four times the keys in the clusters
+fn spread(clusters: u32, size: usize) -> u32 {
+    let spread = (size / clusters as usize + 1).saturating_mul(4);
+    spread.min(u32::MAX as usize) as u32
+}
+
+// An odd multiplier is a bijection on u32
+fn zipf_key(rank: u32) -> i32 {
+    rank.wrapping_mul(0x9e37_79b1) as i32
+}
+
+// A rank in 1..=n with probability close to 1 / rank^exponent, found by
+// inverting the distribution function of the continuous power law
+fn zipf_rank(rng: &mut StdRng, exponent: f64, n: f64) -> u32 {
+    let u: f64 = rng.gen();
+    let x = if (exponent - 1.0).abs() < 1e-9 {
+        (n + 1.0).powf(u)
+    } else {
+        let e = 1.0 - exponent;
+        (((n + 1.0).powf(e) - 1.0) * u + 1.0).powf(1.0 / e)
+    };
+    (x as u32).clamp(1, n as u32)
+}
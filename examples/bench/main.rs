@@ -0,0 +1,136 @@
+// Runs a randomized insert/find/remove workload on several balancing
+// policies and prints the counters side by side:
+//
+//     cargo run --release --example bench -- --help
+
+mod args;
+mod keys;
+mod report;
+mod trace;
+
+use std::time::Instant;
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use wavl::{Avl, Balance, Boxed, RedBlack, Tree, Unbalanced, Wavl};
+
+use args::{Args, Policy, USAGE};
+use keys::Keys;
+use report::Report;
+use trace::Op;
+
+// Runs the same keys and operations on a tree with balancing policy `B`
+fn run<B: Balance>(keys: &[i32], ops: &[Op]) -> Report {
+    let mut report = Report::default();
+
+    let start = Instant::now();
+    let mut tree: Tree<i32, (), Boxed, B> = Tree::default();
+    for &key in keys {
+        tree.insert(key);
+    }
+    report.fill_time = start.elapsed();
+
+    let start = Instant::now();
+    for op in ops {
+        tree.reset_stats();
+        let counts = match *op {
+            Op::Insert(key) => {
+                tree.insert(key);
+                &mut report.insertions
+            }
+            Op::Find(key) => {
+                tree.find(&key);
+                &mut report.searches
+            }
+            Op::Remove(key) => {
+                tree.remove(&key);
+                &mut report.deletions
+            }
+        };
+        counts.count += 1;
+        let stats = tree.stats();
+        counts.rotations.add(stats.rotations());
+        counts.promotions.add(stats.promotions);
+        counts.demotions.add(stats.demotions);
+        counts.nodes.add(stats.accessed_nodes);
+    }
+    report.ops_time = start.elapsed();
+
+    report.node_count = tree.node_count();
+    report.height = tree.height();
+    report
+}
+
+// The keys of the initial tree and the operations, every random choice comes
+// from the one seeded generator. The tree is filled with distinct keys, the
+// distribution itself only drives the operations.
+fn generate(args: &Args, seed: u64) -> Result<(Vec<i32>, Vec<Op>), String> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut generator = Keys::new(args.keys, args.workload, &mut rng);
+    let keys = generator.distinct(args.size, &mut rng)?;
+
+    let mut ops = vec![];
+    for _ in 0..args.ops {
+        let roll = rng.gen_range(0..100);
+        ops.push(if roll < args.mix.insert {
+            Op::Insert(generator.next(&mut rng))
+        } else if roll < args.mix.insert + args.mix.find {
+            Op::Find(generator.next(&mut rng))
+        } else {
+            Op::Remove(keys[rng.gen_range(0..keys.len())])
+        });
+    }
+    Ok((keys, ops))
+}
+
+fn main() {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            std::process::exit(2);
+        }
+    };
+    let (keys, ops, seed) = match &args.replay {
+        Some(path) => {
+            let (keys, ops) = trace::read(path).unwrap_or_else(|message| {
+                eprintln!("{}", message);
+                std::process::exit(1);
+            });
+            (keys, ops, None)
+        }
+        None => {
+            let seed = args.seed.unwrap_or_else(|| rand::thread_rng().gen());
+            eprintln!("seed: {}", seed);
+            let (keys, ops) = generate(&args, seed).unwrap_or_else(|message| {
+                eprintln!("{}", message);
+                std::process::exit(1);
+            });
+            if let Some(path) = &args.record {
+                let flags: Vec<String> = std::env::args().skip(1).collect();
+                let comment = format!("bench {} (seed {})", flags.join(" "), seed);
+                if let Err(error) = trace::write(path, &comment, &keys, &ops) {
+                    eprintln!("Cannot write {}: {}", path, error);
+                    std::process::exit(1);
+                }
+            }
+            (keys, ops, Some(seed))
+        }
+    };
+
+    let reports: Vec<_> = args
+        .policies
+        .iter()
+        .map(|&policy| {
+            let report = match policy {
+                Policy::Wavl => run::<Wavl>(&keys, &ops),
+                Policy::Avl => run::<Avl>(&keys, &ops),
+                Policy::RedBlack => run::<RedBlack>(&keys, &ops),
+                Policy::Unbalanced => run::<Unbalanced>(&keys, &ops),
+            };
+            (policy.name(), report)
+        })
+        .collect();
+
+    report::print(args.format, seed, &reports);
+}
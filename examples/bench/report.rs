@@ -0,0 +1,214 @@
+// What a benchmark run measured, printed as a table, JSON or CSV
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+// One counter over all operations of a kind
+#[derive(Default)]
+pub struct Metric {
+    pub total: usize,
+    pub max: usize,
+    // Number of operations per counter value, only for values that occurred
+    pub histogram: BTreeMap<usize, usize>,
+}
+
+impl Metric {
+    pub fn add(&mut self, value: usize) {
+        self.total += value;
+        self.max = self.max.max(value);
+        *self.histogram.entry(value).or_insert(0) += 1;
+    }
+
+    fn mean(&self, count: usize) -> f64 {
+        if count == 0 {
+            0.0
+        } else {
+            self.total as f64 / count as f64
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Counts {
+    pub count: usize,
+    pub rotations: Metric,
+    pub promotions: Metric,
+    pub demotions: Metric,
+    pub nodes: Metric,
+}
+
+impl Counts {
+    fn metrics(&self) -> [(&'static str, &Metric); 4] {
+        [
+            ("rotations", &self.rotations),
+            ("promotions", &self.promotions),
+            ("demotions", &self.demotions),
+            ("nodes", &self.nodes),
+        ]
+    }
+}
+
+#[derive(Default)]
+pub struct Report {
+    pub node_count: usize,
+    pub height: usize,
+    // Wall-clock time to build the initial tree and to run the operations
+    pub fill_time: Duration,
+    pub ops_time: Duration,
+    pub insertions: Counts,
+    pub deletions: Counts,
+    pub searches: Counts,
+}
+
+impl Report {
+    fn operations(&self) -> [(&'static str, &Counts); 3] {
+        [
+            ("insert", &self.insertions),
+            ("remove", &self.deletions),
+            ("find", &self.searches),
+        ]
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+// A line of the table, one value per policy
+type Row = (&'static str, fn(&Report) -> usize);
+
+pub fn print(format: Format, seed: Option<u64>, reports: &[(&str, Report)]) {
+    match format {
+        Format::Table => print_table(reports),
+        Format::Json => print_json(seed, reports),
+        Format::Csv => print_csv(reports),
+    }
+}
+
+fn print_table(reports: &[(&str, Report)]) {
+    print!("{:<28}", "");
+    for (name, _) in reports {
+        print!("{:>16}", name);
+    }
+    println!();
+
+    let rows: [Row; 15] = [
+        ("Tree count", |r| r.node_count),
+        ("Tree height", |r| r.height),
+        ("Insertions", |r| r.insertions.count),
+        ("Insertion rotations", |r| r.insertions.rotations.total),
+        ("Insertion promotions", |r| r.insertions.promotions.total),
+        ("Insertion demotions", |r| r.insertions.demotions.total),
+        ("Insertion nodes", |r| r.insertions.nodes.total),
+        ("Deletions", |r| r.deletions.count),
+        ("Deletion rotations", |r| r.deletions.rotations.total),
+        ("Deletion promotions", |r| r.deletions.promotions.total),
+        ("Deletion demotions", |r| r.deletions.demotions.total),
+        ("Deletion nodes", |r| r.deletions.nodes.total),
+        ("Searches", |r| r.searches.count),
+        ("Search nodes", |r| r.searches.nodes.total),
+        ("Operations time (us)", |r| r.ops_time.as_micros() as usize),
+    ];
+    for (label, value) in rows {
+        print!("{:<28}", label);
+        for (_, report) in reports {
+            print!("{:>16}", value(report));
+        }
+        println!();
+    }
+}
+
+fn print_json(seed: Option<u64>, reports: &[(&str, Report)]) {
+    let seed = seed.map_or("null".to_string(), |seed| seed.to_string());
+    println!("{{");
+    println!("  \"seed\": {},", seed);
+    println!("  \"policies\": [");
+    for (i, (name, report)) in reports.iter().enumerate() {
+        println!("    {{");
+        println!("      \"policy\": \"{}\",", name);
+        println!("      \"node_count\": {},", report.node_count);
+        println!("      \"height\": {},", report.height);
+        println!(
+            "      \"fill_seconds\": {},",
+            report.fill_time.as_secs_f64()
+        );
+        println!("      \"ops_seconds\": {},", report.ops_time.as_secs_f64());
+        println!("      \"operations\": {{");
+        let operations = report.operations();
+        for (j, (kind, counts)) in operations.iter().enumerate() {
+            println!("        \"{}\": {{", kind);
+            println!("          \"count\": {},", counts.count);
+            let metrics = counts.metrics();
+            for (k, (metric_name, metric)) in metrics.iter().enumerate() {
+                println!(
+                    "          \"{}\": {{\"total\": {}, \"mean\": {}, \"max\": {}, \"histogram\": [{}]}}{}",
+                    metric_name,
+                    metric.total,
+                    metric.mean(counts.count),
+                    metric.max,
+                    join(&metric.histogram, |value, operations| format!("[{}, {}]", value, operations), ", "),
+                    comma(k, metrics.len())
+                );
+            }
+            println!("        }}{}", comma(j, operations.len()));
+        }
+        println!("      }}");
+        println!("    }}{}", comma(i, reports.len()));
+    }
+    println!("  ]");
+    println!("}}");
+}
+
+// One row per policy, operation kind and counter. The histogram holds
+// `value:operations` pairs for the values that occurred, separated by spaces.
+fn print_csv(reports: &[(&str, Report)]) {
+    println!("policy,node_count,height,fill_seconds,ops_seconds,operation,count,metric,total,mean,max,histogram");
+    for (name, report) in reports {
+        for (kind, counts) in report.operations() {
+            for (metric_name, metric) in counts.metrics() {
+                println!(
+                    "{},{},{},{},{},{},{},{},{},{},{},{}",
+                    name,
+                    report.node_count,
+                    report.height,
+                    report.fill_time.as_secs_f64(),
+                    report.ops_time.as_secs_f64(),
+                    kind,
+                    counts.count,
+                    metric_name,
+                    metric.total,
+                    metric.mean(counts.count),
+                    metric.max,
+                    join(
+                        &metric.histogram,
+                        |value, operations| format!("{}:{}", value, operations),
+                        " "
+                    )
+                );
+            }
+        }
+    }
+}
+
+fn join(
+    histogram: &BTreeMap<usize, usize>,
+    pair: fn(usize, usize) -> String,
+    separator: &str,
+) -> String {
+    histogram
+        .iter()
+        .map(|(&value, &operations)| pair(value, operations))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn comma(index: usize, len: usize) -> &'static str {
+    if index + 1 < len {
+        ","
+    } else {
+        ""
+    }
+}
@@ -0,0 +1,53 @@
+// Recorded workloads. A trace is a text file with one entry per line: `k KEY`
+// for a key of the initial tree, then `i KEY`, `f KEY` or `r KEY` for an
+// insert, find or remove. Lines starting with `#` are comments.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+// One operation of the workload
+pub enum Op {
+    Insert(i32),
+    Find(i32),
+    Remove(i32),
+}
+
+pub fn write(path: &str, comment: &str, keys: &[i32], ops: &[Op]) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, "# {}", comment)?;
+    for key in keys {
+        writeln!(out, "k {}", key)?;
+    }
+    for op in ops {
+        match op {
+            Op::Insert(key) => writeln!(out, "i {}", key)?,
+            Op::Find(key) => writeln!(out, "f {}", key)?,
+            Op::Remove(key) => writeln!(out, "r {}", key)?,
+        }
+    }
+    out.flush()
+}
+
+pub fn read(path: &str) -> Result<(Vec<i32>, Vec<Op>), String> {
+    let file = File::open(path).map_err(|error| format!("Cannot open {}: {}", path, error))?;
+    let mut keys = vec![];
+    let mut ops = vec![];
+    for (number, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.map_err(|error| format!("Cannot read {}: {}", path, error))?;
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let invalid = || format!("{}:{}: invalid trace line `{}`", path, number + 1, line);
+        let (kind, key) = line.split_once(' ').ok_or_else(invalid)?;
+        let key: i32 = key.parse().map_err(|_| invalid())?;
+        match kind {
+            "k" if ops.is_empty() => keys.push(key),
+            "i" => ops.push(Op::Insert(key)),
+            "f" => ops.push(Op::Find(key)),
+            "r" => ops.push(Op::Remove(key)),
+            _ => return Err(invalid()),
+        }
+    }
+    Ok((keys, ops))
+}
@@ -0,0 +1,41 @@
+// Writes one DOT file per operation, rotation and rank change while filling a
+// tree, ready to be rendered and strung together into an animation:
+//
+//     cargo run --example frames -- out 1 2 3 4 5 -3
+//     for f in out/*.dot; do dot -Tpng "$f" -o "${f%.dot}.png"; done
+//
+// Negative numbers remove the key instead.
+
+use std::fs;
+use std::path::Path;
+
+use wavl::{TraceFormat, Tree};
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+    let dir = args.next().unwrap_or_else(|| "frames".to_string());
+    let keys: Vec<i32> = args
+        .map(|arg| arg.parse().expect("Keys are integers"))
+        .collect();
+
+    let mut tree: Tree<i32> = Tree::new();
+    tree.start_trace(TraceFormat::Dot);
+    for key in keys {
+        if key < 0 {
+            tree.remove(&-key);
+        } else {
+            tree.insert(key);
+        }
+    }
+
+    fs::create_dir_all(&dir).expect("Cannot create the output directory");
+    let frames = tree.stop_trace();
+    for frame in &frames {
+        let path = Path::new(&dir).join(format!("{:04}.dot", frame.number));
+        fs::write(&path, &frame.snapshot).expect("Cannot write a frame");
+        match frame.step {
+            Some(step) => println!("{}: {} ({})", path.display(), frame.label, step),
+            None => println!("{}: {}", path.display(), frame.label),
+        }
+    }
+}
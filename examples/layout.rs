@@ -0,0 +1,74 @@
+// Times the same workload on the boxed and the arena node layouts:
+//
+//     cargo run --release --example layout
+
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use wavl::{Arena, Boxed, Storage, Tree};
+
+const NODES_COUNT: usize = 1_000_000;
+const SEED: u64 = 0x1a70;
+
+struct Timings {
+    insert: Duration,
+    find: Duration,
+    remove: Duration,
+    drop: Duration,
+}
+
+fn run<A: Storage>(keys: &[i32]) -> Timings {
+    let start = Instant::now();
+    let mut tree: Tree<i32, (), A> = Tree::default();
+    for &key in keys {
+        tree.insert(key);
+    }
+    let insert = start.elapsed();
+
+    let start = Instant::now();
+    for key in keys.iter().rev() {
+        assert!(tree.find(key));
+    }
+    let find = start.elapsed();
+
+    // Remove half and put new keys into the freed space
+    let start = Instant::now();
+    for key in keys.iter().step_by(2) {
+        tree.remove(key);
+    }
+    for key in keys.iter().step_by(2) {
+        tree.insert(key.wrapping_add(1));
+    }
+    let remove = start.elapsed();
+
+    let start = Instant::now();
+    drop(tree);
+    let drop = start.elapsed();
+
+    Timings {
+        insert,
+        find,
+        remove,
+        drop,
+    }
+}
+
+fn main() {
+    let mut rng = StdRng::seed_from_u64(SEED);
+    let keys: Vec<i32> = (0..NODES_COUNT).map(|_| rng.gen()).collect();
+
+    println!(
+        "{:<8} {:>12} {:>12} {:>16} {:>12}",
+        "layout", "insert", "find", "remove+insert", "drop"
+    );
+    for (name, timings) in [
+        ("boxed", run::<Boxed>(&keys)),
+        ("arena", run::<Arena>(&keys)),
+    ] {
+        println!(
+            "{:<8} {:>12.2?} {:>12.2?} {:>16.2?} {:>12.2?}",
+            name, timings.insert, timings.find, timings.remove, timings.drop
+        );
+    }
+}
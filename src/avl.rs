@@ -0,0 +1,84 @@
+// AVL trees in rank-balanced form: the rank of a node is its height, every
+// rank difference is 1 or 2 and no node is 2,2. Insertion rebalances exactly
+// like WAVL insertion, only deletion differs, it has to repair 2,2 nodes and
+// may rotate all the way up to the root.
+
+use crate::node::Link;
+use crate::{Balance, Case, Storage, Summary, Tree};
+
+impl<K, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    pub(crate) fn avl_balance_deleted(
+        &mut self,
+        mut node: Link<K, S, A>,
+        mut parent: Link<K, S, A>,
+    ) {
+        while let Some(p) = parent {
+            let parent_rank = self.node(p).rank;
+            let node_difference = parent_rank - self.rank(node);
+            let sibling = self.get_node_sibling(node, p);
+            let sibling_difference = parent_rank - self.rank(sibling);
+
+            if node_difference == 1 || (node_difference == 2 && sibling_difference == 1) {
+                break;
+            }
+
+            if node_difference == 2 {
+                // The parent is 2,2 and its subtree got shorter
+                self.rebalance_step(Case::DeleteDemote);
+                self.demote(p);
+
+                node = Some(p);
+                parent = self.node(p).parent;
+                continue;
+            }
+
+            // `node` is a 3-child, its sibling a 1-child
+            let s = sibling.expect("A 3-child has a sibling");
+            let sibling_is_right = self.node(p).right == Some(s);
+            let (inner, outer) = if sibling_is_right {
+                (self.node(s).left, self.node(s).right)
+            } else {
+                (self.node(s).right, self.node(s).left)
+            };
+            let sibling_rank = self.node(s).rank;
+
+            if sibling_rank - self.rank(outer) == 1 {
+                self.rebalance_step(Case::DeleteRotate);
+                if sibling_is_right {
+                    self.rotate_left(p);
+                } else {
+                    self.rotate_right(p);
+                }
+
+                if sibling_rank - self.rank(inner) == 1 {
+                    // The subtree keeps its height
+                    self.demote(p);
+                    self.promote(s);
+                    break;
+                }
+                self.demote(p);
+                self.demote(p);
+
+                node = Some(s);
+                parent = self.node(s).parent;
+            } else {
+                let t = inner.expect("A 1-child exists");
+                self.rebalance_step(Case::DeleteDoubleRotate);
+                if sibling_is_right {
+                    self.rotate_right(s);
+                    self.rotate_left(p);
+                } else {
+                    self.rotate_left(s);
+                    self.rotate_right(p);
+                }
+                self.demote(p);
+                self.demote(p);
+                self.demote(s);
+                self.promote(t);
+
+                node = Some(t);
+                parent = self.node(t).parent;
+            }
+        }
+    }
+}
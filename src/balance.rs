@@ -0,0 +1,258 @@
+use crate::check::{Rule, Side};
+use crate::node::{Link, NodePtr};
+use crate::{Storage, Summary, Tree};
+
+/// How a `Tree` keeps itself balanced.
+///
+/// The policy is a type parameter, so one program can run the same workload
+/// on several policies side by side. The trait is sealed, the rebalancing
+/// works on the tree internals.
+pub trait Balance: sealed::Sealed {}
+
+/// Weak AVL rank balancing, the default.
+///
+/// Every rank difference is 1 or 2 and leaves have rank 1. An insertion or
+/// deletion does at most two rotations.
+pub struct Wavl;
+
+/// AVL balancing in rank-balanced form, the rank of a node is its height.
+///
+/// Every rank difference is 1 or 2 and no node is 2,2. Insertion is the same
+/// as for WAVL, a deletion may rotate at every level up to the root.
+pub struct Avl;
+
+/// Red-black balancing in rank-balanced form, the rank of a node is its black
+/// height.
+///
+/// Every rank difference is 0 or 1, a missing child is a 1-child and no
+/// 0-child has a 0-child. 0-children are the red nodes.
+pub struct RedBlack;
+
+/// No rebalancing at all, a plain binary search tree.
+///
+/// Useful as a baseline. Sorted input degenerates it into a list, every node
+/// keeps rank 1.
+pub struct Unbalanced;
+
+impl Balance for Wavl {}
+
+impl Balance for Avl {}
+
+impl Balance for RedBlack {}
+
+impl Balance for Unbalanced {}
+
+pub(crate) mod sealed {
+    use crate::check::Rule;
+    use crate::node::{Link, NodePtr};
+    use crate::{Storage, Summary, Tree};
+
+    pub trait Sealed: Sized {
+        // Restores the rank rules after `node` was linked in as a new leaf
+        fn inserted<K, S: Summary<K>, A: Storage>(
+            tree: &mut Tree<K, S, A, Self>,
+            node: NodePtr<K, S, A>,
+        );
+
+        // Restores the rank rules after `node` took the place of a removed
+        // node below `parent`
+        fn deleted<K, S: Summary<K>, A: Storage>(
+            tree: &mut Tree<K, S, A, Self>,
+            node: Link<K, S, A>,
+            parent: Link<K, S, A>,
+        );
+
+        // Checks the rank rules between `node` and its children
+        fn check_ranks<K, S, A: Storage>(
+            tree: &Tree<K, S, A, Self>,
+            node: NodePtr<K, S, A>,
+        ) -> Result<(), Rule>;
+
+        // Rank of a node built above two subtrees of ranks `left` and
+        // `right` whose sizes differ by at most one, or above the two
+        // subtrees a join puts together
+        fn built_rank(left: i32, right: i32) -> i32;
+
+        // Highest rank of the node on the spine of the taller tree that a
+        // join hangs below its new node, next to a tree of rank `other`
+        fn spine_rank(other: i32) -> i32;
+    }
+}
+
+// Rank differences of 1 or 2 and leaves at rank 1, shared by WAVL and AVL
+fn check_weak_ranks<K, S, A: Storage, B: Balance>(
+    tree: &Tree<K, S, A, B>,
+    node: NodePtr<K, S, A>,
+) -> Result<(), Rule> {
+    let node_ref = tree.node(node);
+    for (side, child) in [(Side::Left, node_ref.left), (Side::Right, node_ref.right)] {
+        let difference = node_ref.rank - tree.rank(child);
+        if difference != 1 && difference != 2 {
+            return Err(Rule::RankDifference { side, difference });
+        }
+    }
+
+    if node_ref.left.is_none() && node_ref.right.is_none() && node_ref.rank != 1 {
+        return Err(Rule::LeafRank {
+            rank: node_ref.rank,
+        });
+    }
+    Ok(())
+}
+
+impl sealed::Sealed for Wavl {
+    fn inserted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) {
+        tree.balance_inserted(node);
+    }
+
+    fn deleted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: Link<K, S, A>,
+        parent: Link<K, S, A>,
+    ) {
+        tree.balance_deleted(node, parent);
+    }
+
+    fn check_ranks<K, S, A: Storage>(
+        tree: &Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) -> Result<(), Rule> {
+        check_weak_ranks(tree, node)
+    }
+
+    // The height, leaves at rank 1 and no 2,2 node
+    fn built_rank(left: i32, right: i32) -> i32 {
+        1 + left.max(right)
+    }
+
+    // The new node takes a rank one above its higher child
+    fn spine_rank(other: i32) -> i32 {
+        other + 1
+    }
+}
+
+impl sealed::Sealed for Avl {
+    fn inserted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) {
+        tree.balance_inserted(node);
+    }
+
+    fn deleted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: Link<K, S, A>,
+        parent: Link<K, S, A>,
+    ) {
+        tree.avl_balance_deleted(node, parent);
+    }
+
+    fn check_ranks<K, S, A: Storage>(
+        tree: &Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) -> Result<(), Rule> {
+        check_weak_ranks(tree, node)?;
+
+        let node_ref = tree.node(node);
+        if node_ref.rank - tree.rank(node_ref.left) == 2
+            && node_ref.rank - tree.rank(node_ref.right) == 2
+        {
+            return Err(Rule::TwoTwoNode);
+        }
+        Ok(())
+    }
+
+    fn built_rank(left: i32, right: i32) -> i32 {
+        1 + left.max(right)
+    }
+
+    fn spine_rank(other: i32) -> i32 {
+        other + 1
+    }
+}
+
+impl sealed::Sealed for RedBlack {
+    fn inserted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) {
+        tree.rb_balance_inserted(node);
+    }
+
+    fn deleted<K, S: Summary<K>, A: Storage>(
+        tree: &mut Tree<K, S, A, Self>,
+        node: Link<K, S, A>,
+        parent: Link<K, S, A>,
+    ) {
+        tree.rb_balance_deleted(node, parent);
+    }
+
+    fn check_ranks<K, S, A: Storage>(
+        tree: &Tree<K, S, A, Self>,
+        node: NodePtr<K, S, A>,
+    ) -> Result<(), Rule> {
+        let node_ref = tree.node(node);
+        let zero_child = node_ref
+            .parent
+            .is_some_and(|parent| tree.node(parent).rank == node_ref.rank);
+        for (side, child) in [(Side::Left, node_ref.left), (Side::Right, node_ref.right)] {
+            let difference = node_ref.rank - tree.rank(child);
+            let allowed = match child {
+                Some(_) => difference == 0 || difference == 1,
+                None => difference == 1,
+            };
+            if !allowed {
+                return Err(Rule::RankDifference { side, difference });
+            }
+            if zero_child && child.is_some() && difference == 0 {
+                return Err(Rule::ZeroChain { side });
+            }
+        }
+        Ok(())
+    }
+
+    // The shortest path down: the deeper levels of a midpoint-split tree are
+    // its 0-children, and no two of them are in a row
+    fn built_rank(left: i32, right: i32) -> i32 {
+        1 + left.min(right)
+    }
+
+    // A node of equal black height, the new node joins them as a 0-child
+    fn spine_rank(other: i32) -> i32 {
+        other
+    }
+}
+
+impl sealed::Sealed for Unbalanced {
+    fn inserted<K, S: Summary<K>, A: Storage>(
+        _tree: &mut Tree<K, S, A, Self>,
+        _node: NodePtr<K, S, A>,
+    ) {
+    }
+
+    fn deleted<K, S: Summary<K>, A: Storage>(
+        _tree: &mut Tree<K, S, A, Self>,
+        _node: Link<K, S, A>,
+        _parent: Link<K, S, A>,
+    ) {
+    }
+
+    fn check_ranks<K, S, A: Storage>(
+        _tree: &Tree<K, S, A, Self>,
+        _node: NodePtr<K, S, A>,
+    ) -> Result<(), Rule> {
+        Ok(())
+    }
+
+    fn built_rank(_left: i32, _right: i32) -> i32 {
+        1
+    }
+
+    // Every rank is 1, the new node becomes the root
+    fn spine_rank(other: i32) -> i32 {
+        other + 1
+    }
+}
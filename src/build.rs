@@ -0,0 +1,82 @@
+use crate::node::{Link, Node};
+use crate::{Balance, Storage, Summary, Tree};
+
+impl<K: Ord, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Builds a tree from keys in strictly increasing order in O(n), without
+    /// comparisons beyond checking the order and without rebalancing.
+    ///
+    /// The keys are split at the middle at every level, for WAVL and AVL the
+    /// rank of each node is its height with all leaves at rank 1.
+    ///
+    /// Panics if the keys are not strictly increasing.
+    pub fn from_sorted_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let keys: Vec<K> = iter.into_iter().collect();
+        assert!(
+            keys.windows(2).all(|pair| pair[0] < pair[1]),
+            "from_sorted_iter needs strictly increasing keys"
+        );
+        let mut tree = Self::default();
+        tree.build(keys);
+        tree
+    }
+
+    // The tree must be empty and the keys strictly increasing
+    fn build(&mut self, keys: Vec<K>) {
+        self.count = keys.len();
+        self.root = self.build_subtree(&mut keys.into_iter(), self.count);
+    }
+
+    // Takes the next `len` keys, the left half goes below the middle one
+    fn build_subtree(&mut self, keys: &mut impl Iterator<Item = K>, len: usize) -> Link<K, S, A> {
+        if len == 0 {
+            return None;
+        }
+        let left_len = (len - 1) / 2;
+        let left = self.build_subtree(keys, left_len);
+        let key = keys.next().expect("The builder counted the keys");
+        let node = self.alloc_node(Node::new(key, None));
+        let right = self.build_subtree(keys, len - 1 - left_len);
+
+        for child in [left, right].into_iter().flatten() {
+            self.node_mut(child).parent = Some(node);
+        }
+        let rank = B::built_rank(self.rank(left), self.rank(right));
+        let node_mut = self.node_mut(node);
+        node_mut.left = left;
+        node_mut.right = right;
+        node_mut.rank = rank;
+        self.update_node(node);
+        Some(node)
+    }
+}
+
+// Sorted input is built in O(n) and appended to what is there, anything else
+// is inserted key by key. Of equal keys the first one stays, as with `insert`.
+impl<K: Ord, S: Summary<K>, A: Storage, B: Balance> FromIterator<K> for Tree<K, S, A, B> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut tree = Self::default();
+        tree.extend(iter);
+        tree
+    }
+}
+
+impl<K: Ord, S: Summary<K>, A: Storage, B: Balance> Extend<K> for Tree<K, S, A, B> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        let mut keys: Vec<K> = iter.into_iter().collect();
+        if keys.windows(2).all(|pair| pair[0] <= pair[1]) {
+            keys.dedup();
+            if self.root.is_none() {
+                self.build(keys);
+            } else {
+                // Joined in O(log n) when the keys lie past either end
+                let mut other = Self::default();
+                other.build(keys);
+                self.append(&mut other);
+            }
+        } else {
+            for key in keys {
+                self.insert(key);
+            }
+        }
+    }
+}
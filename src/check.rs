@@ -0,0 +1,233 @@
+use std::fmt;
+
+use crate::node::NodePtr;
+use crate::{Balance, Storage, Tree};
+
+/// A broken tree invariant found by `Tree::check_invariants`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvariantError<K> {
+    /// Key of the offending node, `None` when the rule is about the whole tree.
+    pub key: Option<K>,
+    pub rule: Rule,
+}
+
+/// The invariant that an `InvariantError` reports as broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// The rank difference to a child (or a missing child) breaks the rules
+    /// of the balancing policy, for WAVL it is not 1 or 2.
+    RankDifference { side: Side, difference: i32 },
+    /// A node without children does not have rank 1.
+    LeafRank { rank: i32 },
+    /// Both rank differences are 2, which AVL trees do not allow.
+    TwoTwoNode,
+    /// A 0-child has a 0-child of its own on `side`, two red nodes in a row
+    /// in red-black terms.
+    ZeroChain { side: Side },
+    /// A child does not point back at its parent.
+    ParentPointer { side: Side },
+    /// The root has a parent link.
+    RootParent,
+    /// The key is not greater than the key before it in order.
+    KeyOrder,
+    /// The stored subtree size does not match the sizes of the children.
+    SubtreeSize { size: usize, expected: usize },
+    /// `count` does not match the number of nodes reachable from the root.
+    Count { count: usize, reachable: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl<K: fmt::Debug> fmt::Display for InvariantError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.rule {
+            Rule::RankDifference { side, difference } => {
+                write!(f, "rank difference {} to {:?} child", difference, side)?
+            }
+            Rule::LeafRank { rank } => write!(f, "leaf has rank {}", rank)?,
+            Rule::TwoTwoNode => write!(f, "node is 2,2")?,
+            Rule::ZeroChain { side } => write!(f, "0-child has a 0-child on the {:?} side", side)?,
+            Rule::ParentPointer { side } => {
+                write!(f, "{:?} child does not point back to its parent", side)?
+            }
+            Rule::RootParent => write!(f, "root has a parent")?,
+            Rule::KeyOrder => write!(f, "key is out of order")?,
+            Rule::SubtreeSize { size, expected } => {
+                write!(f, "subtree size is {} instead of {}", size, expected)?
+            }
+            Rule::Count { count, reachable } => write!(
+                f,
+                "count is {} but {} nodes are reachable",
+                count, reachable
+            )?,
+        }
+        if let Some(key) = &self.key {
+            write!(f, " at key {:?}", key)?;
+        }
+        Ok(())
+    }
+}
+
+impl<K: fmt::Debug> std::error::Error for InvariantError<K> {}
+
+impl<K: Ord, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Walks every node and checks the rank rules of the balancing policy,
+    /// the parent links, the key order, the subtree sizes and the node count,
+    /// reporting the first broken one.
+    pub fn check_invariants(&self) -> Result<(), InvariantError<&K>> {
+        if let Some(root) = self.root {
+            let root_ref = self.node(root);
+            if root_ref.parent.is_some() {
+                return Err(InvariantError {
+                    key: Some(&root_ref.data),
+                    rule: Rule::RootParent,
+                });
+            }
+        }
+
+        let mut reachable = 0;
+        let mut prev: Option<&K> = None;
+        let mut stack = vec![];
+        let mut node = self.root;
+
+        loop {
+            while let Some(current) = node {
+                stack.push(current);
+                node = self.node(current).left;
+            }
+
+            let top = match stack.pop() {
+                Some(top) => top,
+                None => break,
+            };
+            let top_ref = self.node(top);
+
+            self.check_node(top)?;
+            if prev.is_some_and(|prev| *prev >= top_ref.data) {
+                return Err(InvariantError {
+                    key: Some(&top_ref.data),
+                    rule: Rule::KeyOrder,
+                });
+            }
+            prev = Some(&top_ref.data);
+            reachable += 1;
+
+            node = top_ref.right;
+        }
+
+        if reachable != self.count {
+            return Err(InvariantError {
+                key: None,
+                rule: Rule::Count {
+                    count: self.count,
+                    reachable,
+                },
+            });
+        }
+
+        Ok(())
+    }
+
+    fn check_node(&self, node: NodePtr<K, S, A>) -> Result<(), InvariantError<&K>> {
+        let node_ref = self.node(node);
+        let error = |rule| {
+            Err(InvariantError {
+                key: Some(&node_ref.data),
+                rule,
+            })
+        };
+
+        for (side, child) in [(Side::Left, node_ref.left), (Side::Right, node_ref.right)] {
+            if child.is_some_and(|child| self.node(child).parent != Some(node)) {
+                return error(Rule::ParentPointer { side });
+            }
+        }
+
+        // The rank rules depend on the balancing policy
+        if let Err(rule) = B::check_ranks(self, node) {
+            return error(rule);
+        }
+
+        #[cfg(feature = "order-statistics")]
+        {
+            let expected = self.subtree_size(node_ref.left) + self.subtree_size(node_ref.right) + 1;
+            if node_ref.size != expected {
+                return error(Rule::SubtreeSize {
+                    size: node_ref.size,
+                    expected,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_broken_rank() {
+        let mut tree = Tree::new();
+        for key in 0..3 {
+            tree.insert(key);
+        }
+        assert_eq!(tree.check_invariants(), Ok(()));
+
+        let root = tree.root.unwrap();
+        tree.node_mut(root).rank = 4;
+        assert_eq!(
+            tree.check_invariants(),
+            Err(InvariantError {
+                key: Some(&1),
+                rule: Rule::RankDifference {
+                    side: Side::Left,
+                    difference: 3
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn reports_root_parent() {
+        let mut tree = Tree::new();
+        for key in 0..3 {
+            tree.insert(key);
+        }
+        let root = tree.root.unwrap();
+        let child = tree.node(root).left;
+        tree.node_mut(root).parent = child;
+        assert_eq!(
+            tree.check_invariants(),
+            Err(InvariantError {
+                key: Some(&1),
+                rule: Rule::RootParent,
+            })
+        );
+
+        // Dropping the tree follows the parent links
+        tree.node_mut(root).parent = None;
+    }
+
+    #[test]
+    fn reports_broken_count() {
+        let mut tree = Tree::new();
+        tree.insert(1);
+        tree.count = 2;
+        assert_eq!(
+            tree.check_invariants(),
+            Err(InvariantError {
+                key: None,
+                rule: Rule::Count {
+                    count: 2,
+                    reachable: 1
+                },
+            })
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+use crate::node::NodePtr;
+use crate::{Balance, Storage, Tree};
+
+/// How `Tree::write_dot` labels the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DotLabels {
+    /// Every node shows its rank.
+    Ranks,
+    /// Every edge shows the rank difference between parent and child.
+    RankDifferences,
+}
+
+/// Options of `Tree::write_dot`.
+#[derive(Debug, Clone)]
+pub struct DotOptions<'a, K> {
+    pub labels: DotLabels,
+    /// Fills nodes with rank difference 2 to both children in another colour.
+    pub highlight_two_two: bool,
+    /// Draws missing children as `null` leaves.
+    pub null_leaves: bool,
+    /// Puts nodes of equal rank on one row.
+    pub align_ranks: bool,
+    /// Levels to draw, the root of the drawing is level 1. Cut off subtrees
+    /// are drawn as `...`.
+    pub max_depth: Option<usize>,
+    /// Draws only the subtree below this key, nothing if it is missing.
+    pub subtree: Option<&'a K>,
+}
+
+impl<K> Default for DotOptions<'_, K> {
+    fn default() -> Self {
+        Self {
+            labels: DotLabels::RankDifferences,
+            highlight_two_two: false,
+            null_leaves: true,
+            align_ranks: true,
+            max_depth: None,
+            subtree: None,
+        }
+    }
+}
+
+impl<K: Ord + Display, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Writes the tree in the Graphviz DOT format, render it with
+    /// `dot -Tsvg`.
+    pub fn write_dot(&self, w: &mut impl Write, options: &DotOptions<K>) -> io::Result<()> {
+        // A plain descent, drawing does not count as accessing nodes
+        let mut root = self.root;
+        if let Some(key) = options.subtree {
+            while let Some(node) = root {
+                let node_ref = self.node(node);
+                root = match key.cmp(&node_ref.data) {
+                    Ordering::Less => node_ref.left,
+                    Ordering::Greater => node_ref.right,
+                    Ordering::Equal => break,
+                };
+            }
+        }
+
+        writeln!(w, "digraph Tree {{")?;
+        writeln!(
+            w,
+            "  node [shape=circle, style=filled, fillcolor=lightblue];"
+        )?;
+
+        let mut ids = 0usize;
+        let mut ranks: BTreeMap<i32, Vec<usize>> = BTreeMap::new();
+        let mut stack: Vec<(NodePtr<K, S, A>, usize, usize)> = vec![];
+        if let Some(root) = root {
+            self.write_dot_node(w, options, root, ids)?;
+            ranks.entry(self.node(root).rank).or_default().push(ids);
+            stack.push((root, ids, 1));
+            ids += 1;
+        }
+
+        // Preorder walk with an explicit stack, deep trees must not overflow
+        while let Some((node, id, depth)) = stack.pop() {
+            let node_ref = self.node(node);
+            let mut children = vec![];
+            for child in [node_ref.left, node_ref.right] {
+                if child.is_none() && !options.null_leaves {
+                    continue;
+                }
+                let child_id = ids;
+                ids += 1;
+                match child {
+                    None => writeln!(
+                        w,
+                        "  n{} [label=\"null\", shape=box, fontsize=8, width=0, height=0];",
+                        child_id
+                    )?,
+                    Some(_) if options.max_depth.is_some_and(|max| depth >= max) => {
+                        writeln!(w, "  n{} [label=\"...\", shape=plaintext];", child_id)?
+                    }
+                    Some(child) => {
+                        self.write_dot_node(w, options, child, child_id)?;
+                        let rank = self.node(child).rank;
+                        ranks.entry(rank).or_default().push(child_id);
+                        children.push((child, child_id, depth + 1));
+                    }
+                }
+
+                match options.labels {
+                    DotLabels::RankDifferences => {
+                        let difference = node_ref.rank - self.rank(child);
+                        writeln!(w, "  n{} -> n{} [label=\"{}\"];", id, child_id, difference)?
+                    }
+                    DotLabels::Ranks => writeln!(w, "  n{} -> n{};", id, child_id)?,
+                }
+            }
+            // The left subtree comes out first
+            stack.extend(children.into_iter().rev());
+        }
+
+        if options.align_ranks {
+            for ids in ranks.values() {
+                write!(w, "  {{rank=same;")?;
+                for id in ids {
+                    write!(w, " n{};", id)?;
+                }
+                writeln!(w, "}}")?;
+            }
+        }
+        writeln!(w, "}}")
+    }
+
+    fn write_dot_node(
+        &self,
+        w: &mut impl Write,
+        options: &DotOptions<K>,
+        node: NodePtr<K, S, A>,
+        id: usize,
+    ) -> io::Result<()> {
+        let node_ref = self.node(node);
+        let key = escape(&node_ref.data.to_string());
+        let label = match options.labels {
+            DotLabels::Ranks => format!("{}\\n{}", key, node_ref.rank),
+            DotLabels::RankDifferences => key,
+        };
+        let two_two = node_ref.rank - self.rank(node_ref.left) == 2
+            && node_ref.rank - self.rank(node_ref.right) == 2;
+        if options.highlight_two_two && two_two {
+            writeln!(w, "  n{} [label=\"{}\", fillcolor=orange];", id, label)
+        } else {
+            writeln!(w, "  n{} [label=\"{}\"];", id, label)
+        }
+    }
+}
+
+// Quotes and backslashes would end or break a DOT string
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
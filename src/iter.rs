@@ -0,0 +1,139 @@
+use std::iter::FusedIterator;
+
+use crate::node::Link;
+use crate::{Balance, Boxed, Storage, Tree, Wavl};
+
+/// An in-order iterator over the keys of a `Tree`.
+pub struct Iter<'a, K, S = (), A: Storage = Boxed, B = Wavl> {
+    tree: &'a Tree<K, S, A, B>,
+    front: Link<K, S, A>,
+    back: Link<K, S, A>,
+    remaining: usize,
+}
+
+/// An owning in-order iterator over the keys of a `Tree`.
+pub struct IntoIter<K, S = (), A: Storage = Boxed, B = Wavl> {
+    tree: Tree<K, S, A, B>,
+    front: Link<K, S, A>,
+    back: Link<K, S, A>,
+}
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Iterates over the keys in ascending order.
+    pub fn iter(&self) -> Iter<'_, K, S, A, B> {
+        Iter {
+            tree: self,
+            front: self.root.map(|root| self.leftmost_child(root)),
+            back: self.root.map(|root| self.rightmost_child(root)),
+            remaining: self.count,
+        }
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> Iterator for Iter<'a, K, S, A, B> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let tree = self.tree;
+        let node = self.front?;
+        self.front = tree.successor_of_node(node);
+        self.remaining -= 1;
+        Some(&tree.node(node).data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> DoubleEndedIterator for Iter<'a, K, S, A, B> {
+    fn next_back(&mut self) -> Option<&'a K> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let tree = self.tree;
+        let node = self.back?;
+        self.back = tree.predecessor_of_node(node);
+        self.remaining -= 1;
+        Some(&tree.node(node).data)
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> ExactSizeIterator for Iter<'_, K, S, A, B> {}
+
+impl<K, S, A: Storage, B: Balance> FusedIterator for Iter<'_, K, S, A, B> {}
+
+impl<K, S, A: Storage, B: Balance> Clone for Iter<'_, K, S, A, B> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> Iterator for IntoIter<K, S, A, B> {
+    type Item = K;
+
+    // The front node is always the leftmost one, so it has no left child and
+    // can be unlinked without rebalancing what is about to be dropped anyway.
+    fn next(&mut self) -> Option<K> {
+        if self.tree.count == 0 {
+            return None;
+        }
+
+        let node = self.front?;
+        self.front = self.tree.successor_of_node(node);
+        self.tree.count -= 1;
+        self.tree.unlink_node(node, self.tree.node(node).right);
+        Some(self.tree.free_node(node).data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.tree.count, Some(self.tree.count))
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> DoubleEndedIterator for IntoIter<K, S, A, B> {
+    fn next_back(&mut self) -> Option<K> {
+        if self.tree.count == 0 {
+            return None;
+        }
+
+        let node = self.back?;
+        self.back = self.tree.predecessor_of_node(node);
+        self.tree.count -= 1;
+        self.tree.unlink_node(node, self.tree.node(node).left);
+        Some(self.tree.free_node(node).data)
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> ExactSizeIterator for IntoIter<K, S, A, B> {}
+
+impl<K, S, A: Storage, B: Balance> FusedIterator for IntoIter<K, S, A, B> {}
+
+impl<K, S, A: Storage, B: Balance> IntoIterator for Tree<K, S, A, B> {
+    type Item = K;
+    type IntoIter = IntoIter<K, S, A, B>;
+
+    fn into_iter(self) -> IntoIter<K, S, A, B> {
+        let iter = self.iter();
+        let (front, back) = (iter.front, iter.back);
+        IntoIter {
+            tree: self,
+            front,
+            back,
+        }
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> IntoIterator for &'a Tree<K, S, A, B> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K, S, A, B>;
+
+    fn into_iter(self) -> Iter<'a, K, S, A, B> {
+        self.iter()
+    }
+}
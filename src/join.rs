@@ -0,0 +1,225 @@
+use std::borrow::Borrow;
+
+use crate::node::{Link, Node, NodePtr};
+use crate::{Balance, Storage, Summary, Tree};
+
+impl<K: Ord, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Joins `left`, `key` and `right` into one tree, all keys of `left` must
+    /// be below `key` and all keys of `right` above it.
+    ///
+    /// The ranks tell where `key` goes on the spine of the taller tree, from
+    /// there one insertion rebalancing restores the rank rules, which takes
+    /// O(log n) in all. `Arena` trees first move the nodes of `right` into the
+    /// slab of `left`, which takes O(n). The result keeps the statistics and
+    /// the observer of `left`.
+    ///
+    /// Panics if the keys are out of order.
+    pub fn join(mut left: Self, key: K, mut right: Self) -> Self {
+        let below = left
+            .root
+            .map(|root| &left.node(left.rightmost_child(root)).data);
+        let above = right
+            .root
+            .map(|root| &right.node(right.leftmost_child(root)).data);
+        assert!(
+            below.is_none_or(|below| *below < key) && above.is_none_or(|above| key < *above),
+            "join needs the keys of left below key and the keys of right above it"
+        );
+
+        let right_root = A::transfer(&mut right.store, &mut left.store, right.root.take());
+        let node = left.alloc_node(Node::new(key, None));
+        left.root = Some(left.join_roots(left.root, node, right_root));
+        left.count += std::mem::take(&mut right.count) + 1;
+        left
+    }
+
+    /// Moves all keys of `other` into this tree and leaves `other` empty.
+    ///
+    /// When all keys of one tree are below those of the other, one key is
+    /// taken out of `other` and the trees are joined around it in O(log n),
+    /// or in O(n) for `Arena` trees, which move the nodes of `other` over.
+    /// Otherwise the keys of `other` are inserted one by one, keys already
+    /// present stay as they are.
+    pub fn append(&mut self, other: &mut Self) {
+        let (Some(root), Some(other_root)) = (self.root, other.root) else {
+            if self.root.is_none() {
+                std::mem::swap(&mut self.root, &mut other.root);
+                std::mem::swap(&mut self.store, &mut other.store);
+                std::mem::swap(&mut self.count, &mut other.count);
+            }
+            return;
+        };
+
+        let self_below = self.node(self.rightmost_child(root)).data
+            < other.node(other.leftmost_child(other_root)).data;
+        let other_below = other.node(other.rightmost_child(other_root)).data
+            < self.node(self.leftmost_child(root)).data;
+        if !self_below && !other_below {
+            // Overlapping ranges, the keys of `other` go in one by one
+            let mut rest = Self::default();
+            std::mem::swap(&mut rest.store, &mut other.store);
+            rest.root = other.root.take();
+            rest.count = std::mem::take(&mut other.count);
+            for key in rest {
+                self.insert(key);
+            }
+            return;
+        }
+
+        // The key next to this tree goes in the middle
+        let middle = if self_below {
+            other.leftmost_child(other_root)
+        } else {
+            other.rightmost_child(other_root)
+        };
+        let key = other.take_node(middle);
+        let other_root = A::transfer(&mut other.store, &mut self.store, other.root.take());
+        let node = self.alloc_node(Node::new(key, None));
+        let joined = if self_below {
+            self.join_roots(self.root, node, other_root)
+        } else {
+            self.join_roots(other_root, node, self.root)
+        };
+        self.root = Some(joined);
+        self.count += std::mem::take(&mut other.count) + 1;
+    }
+
+    /// Moves every key at or above `key` into a new tree and keeps the rest.
+    ///
+    /// The tree is cut along the search path for `key` and the pieces on
+    /// either side are joined again bottom-up, O(log n) in all. It takes O(n)
+    /// for `Arena` trees, which move the nodes of the new tree into a slab of
+    /// its own, and without the `order-statistics` feature, where counting
+    /// the keys of the new tree walks over it.
+    pub fn split_off<Q>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        // The nodes on the search path, each with the side it goes to
+        let mut path = vec![];
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            let goes_right = key <= node_ref.data.borrow();
+            path.push((current, goes_right));
+            node = if goes_right {
+                node_ref.left
+            } else {
+                node_ref.right
+            };
+        }
+
+        // Below each path node lie the two halves of its path side so far,
+        // the node and its other subtree join one of them
+        let (mut left, mut right) = (None, None);
+        for (node, goes_right) in path.into_iter().rev() {
+            let node_mut = self.node_mut(node);
+            let (left_child, right_child) = (node_mut.left.take(), node_mut.right.take());
+            node_mut.parent = None;
+            if goes_right {
+                let subtree = self.detach(right_child);
+                right = Some(self.join_roots(right, node, subtree));
+            } else {
+                let subtree = self.detach(left_child);
+                left = Some(self.join_roots(subtree, node, left));
+            }
+        }
+
+        let mut split = Self::default();
+        split.count = self.subtree_len(right);
+        split.root = A::transfer(&mut self.store, &mut split.store, right);
+        self.count -= split.count;
+        self.root = left;
+        split
+    }
+
+    // Keys below `root`, from the subtree sizes if they are kept
+    fn subtree_len(&self, root: Link<K, S, A>) -> usize {
+        #[cfg(feature = "order-statistics")]
+        {
+            self.subtree_size(root)
+        }
+        #[cfg(not(feature = "order-statistics"))]
+        {
+            let mut len = 0;
+            let mut stack: Vec<_> = root.into_iter().collect();
+            while let Some(node) = stack.pop() {
+                len += 1;
+                let node_ref = self.node(node);
+                stack.extend(node_ref.left);
+                stack.extend(node_ref.right);
+            }
+            len
+        }
+    }
+
+    fn detach(&mut self, node: Link<K, S, A>) -> Link<K, S, A> {
+        if let Some(node) = node {
+            self.node_mut(node).parent = None;
+        }
+        node
+    }
+
+    // Joins two detached trees of this store with the detached `node` in the
+    // middle and hands back the root of the result
+    fn join_roots(
+        &mut self,
+        left: Link<K, S, A>,
+        node: NodePtr<K, S, A>,
+        right: Link<K, S, A>,
+    ) -> NodePtr<K, S, A> {
+        // Rotations at the top replace `self.root`, so meanwhile it points at
+        // the taller tree, the one that `node` goes into
+        let root = self.root;
+        let (left_rank, right_rank) = (self.rank(left), self.rank(right));
+        let left_is_taller = left_rank >= right_rank;
+        let taller = if left_is_taller { left } else { right };
+        let (mut parent, mut child) = (None, taller);
+        let spine_rank = B::spine_rank(left_rank.min(right_rank));
+        while let Some(current) = child.filter(|&current| self.node(current).rank > spine_rank) {
+            parent = Some(current);
+            child = if left_is_taller {
+                self.node(current).right
+            } else {
+                self.node(current).left
+            };
+        }
+
+        let (left, right) = if left_is_taller {
+            (child, right)
+        } else {
+            (left, child)
+        };
+        for child in [left, right].into_iter().flatten() {
+            self.node_mut(child).parent = Some(node);
+        }
+        let rank = B::built_rank(self.rank(left), self.rank(right));
+        let node_mut = self.node_mut(node);
+        node_mut.left = left;
+        node_mut.right = right;
+        node_mut.parent = parent;
+        node_mut.rank = rank;
+
+        self.root = match parent {
+            None => Some(node),
+            Some(parent) => {
+                if left_is_taller {
+                    self.node_mut(parent).right = Some(node);
+                } else {
+                    self.node_mut(parent).left = Some(node);
+                }
+                taller
+            }
+        };
+        self.update_node(node);
+        self.update_path(parent);
+
+        self.trace_operation("join", node);
+        B::inserted(self, node);
+
+        let joined = self.root.expect("The joined tree has a root");
+        self.root = root;
+        joined
+    }
+}
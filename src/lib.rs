@@ -0,0 +1,805 @@
+//! A weak AVL (WAVL) tree.
+//!
+//! `Tree` is an ordered set and `WavlMap` an ordered map built on it. Both
+//! keep the rank-balanced invariants of WAVL trees, so insertions and
+//! deletions do at most a constant number of rotations.
+//!
+//! The balancing policy is the `B` type parameter of `Tree`, `Wavl` by
+//! default. `Avl` and `RedBlack` rebalance the same rank-based node layout
+//! the classic ways, `Unbalanced` turns it into a plain binary search tree
+//! to compare against.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::ops::Bound;
+
+mod avl;
+mod balance;
+mod build;
+mod check;
+mod dot;
+mod iter;
+mod join;
+mod map;
+mod node;
+mod observer;
+#[cfg(feature = "order-statistics")]
+mod order;
+mod pretty;
+mod range;
+mod red_black;
+mod stats;
+mod storage;
+mod summary;
+mod trace;
+
+pub use balance::{Avl, Balance, RedBlack, Unbalanced, Wavl};
+pub use check::{InvariantError, Rule, Side};
+pub use dot::{DotLabels, DotOptions};
+pub use iter::{IntoIter, Iter};
+pub use map::{Entry, OccupiedEntry, VacantEntry, WavlMap};
+pub use observer::Observer;
+pub use pretty::{Pretty, PrettyOptions};
+pub use range::Range;
+pub use stats::{Case, Stats};
+pub use storage::{Arena, Boxed, Storage};
+pub use summary::Summary;
+pub use trace::{Frame, TraceFormat};
+
+use node::{Link, Node, NodePtr};
+use observer::ObserverSlot;
+use storage::sealed::Sealed;
+use trace::Tracer;
+
+pub struct Tree<K, S = (), A: Storage = Boxed, B = Wavl> {
+    store: <A as Sealed>::Store<K, S>,
+    balance: PhantomData<B>,
+    count: usize,
+    root: Link<K, S, A>,
+    stats: Stats,
+    observer: ObserverSlot<K>,
+    tracer: Option<Box<Tracer<K, S, A, B>>>,
+}
+
+impl<K> Tree<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> Default for Tree<K, S, A, B> {
+    fn default() -> Self {
+        Self {
+            store: Default::default(),
+            balance: PhantomData,
+            count: 0,
+            stats: Stats::default(),
+            observer: ObserverSlot::default(),
+            tracer: None,
+            root: None,
+        }
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// The rotations, promotions, demotions, rebalancing steps and visited
+    /// nodes counted since the tree was created or since the last
+    /// `reset_stats`.
+    pub fn stats(&self) -> &Stats {
+        &self.stats
+    }
+
+    // Rank of the root node, `None` for an empty tree
+    pub fn root_rank(&self) -> Option<i32> {
+        self.root.map(|root| self.node(root).rank)
+    }
+
+    /// Number of nodes on the longest path down from the root, 0 for an
+    /// empty tree. Unlike the root rank this holds for every policy, it takes
+    /// a walk over the whole tree.
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<_> = self.root.map(|root| (root, 1)).into_iter().collect();
+        while let Some((node, depth)) = stack.pop() {
+            height = height.max(depth);
+            let node_ref = self.node(node);
+            stack.extend(node_ref.left.map(|left| (left, depth + 1)));
+            stack.extend(node_ref.right.map(|right| (right, depth + 1)));
+        }
+        height
+    }
+
+    /// Sets every counter of `stats` back to zero.
+    pub fn reset_stats(&mut self) {
+        self.stats = Stats::default();
+    }
+
+    /// Reports every structural change from now on to `observer`, replacing
+    /// the previous one.
+    ///
+    /// The observer has to be `Send` so that the tree stays `Send`. It need
+    /// not be `Sync`: it is only called while the tree is borrowed mutably,
+    /// and a shared tree stays `Sync` either way.
+    pub fn set_observer(&mut self, observer: impl Observer<K> + Send + 'static) {
+        self.observer.set(Box::new(observer));
+    }
+
+    /// Removes the observer and hands it back.
+    pub fn take_observer(&mut self) -> Option<Box<dyn Observer<K> + Send>> {
+        self.observer.take()
+    }
+
+    // Counts one rebalancing step and its rotations
+    fn rebalance_step(&mut self, case: Case) {
+        self.stats.record(case);
+        if let Some(observer) = self.observer.get_mut() {
+            observer.rebalance(case);
+        }
+        self.trace_step(case);
+    }
+
+    fn increase_nodes(&mut self) {
+        self.stats.accessed_nodes += 1;
+    }
+
+    // InorderWalk()
+    pub fn inorder(&self) -> Vec<(&K, i32)> {
+        let mut v = vec![];
+        let mut node = self.root.map(|root| self.leftmost_child(root));
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            v.push((&node_ref.data, node_ref.rank));
+            node = self.successor_of_node(current);
+        }
+        v
+    }
+
+    pub fn node_count(&self) -> usize {
+        assert!(self.count != 0 || self.root.is_none());
+        self.count
+    }
+
+    // Puts `r` in the place of `node` and returns the parent they share. Ranks,
+    // sizes and summaries are left for the caller to fix.
+    fn unlink_node(&mut self, node: NodePtr<K, S, A>, r: Link<K, S, A>) -> Link<K, S, A> {
+        let parent = self.node(node).parent;
+        if let Some(r) = r {
+            self.node_mut(r).parent = parent;
+        }
+        match parent {
+            // Remove root node
+            None => self.root = r,
+            Some(parent) => {
+                let parent_ref = self.node_mut(parent);
+                if parent_ref.left == Some(node) {
+                    parent_ref.left = r;
+                } else if parent_ref.right == Some(node) {
+                    parent_ref.right = r;
+                }
+            }
+        }
+        parent
+    }
+
+    // `cmp` orders the searched key against the key of a visited node.
+    fn find_node<F>(&mut self, fromnode: Link<K, S, A>, cmp: &mut F) -> Link<K, S, A>
+    where
+        F: FnMut(&K) -> Ordering,
+    {
+        let mut node = fromnode;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            node = match cmp(&node_ref.data) {
+                Ordering::Equal => return Some(current),
+                Ordering::Less => node_ref.left,
+                Ordering::Greater => node_ref.right,
+            };
+            self.increase_nodes();
+        }
+        None
+    }
+
+    // Like `find_node`, but through a shared borrow and without counting
+    // the visited nodes
+    fn lookup_node<F>(&self, cmp: &mut F) -> Link<K, S, A>
+    where
+        F: FnMut(&K) -> Ordering,
+    {
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            node = match cmp(&node_ref.data) {
+                Ordering::Equal => return Some(current),
+                Ordering::Less => node_ref.left,
+                Ordering::Greater => node_ref.right,
+            };
+        }
+        None
+    }
+}
+
+impl<K, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    fn remove_node(&mut self, node: NodePtr<K, S, A>, rebalance: bool) -> K {
+        let node_ref = self.node(node);
+        match (node_ref.left, node_ref.right) {
+            (None, None) => self.replace_node(node, None, rebalance),
+            (Some(_), Some(_)) => {
+                let succ = self
+                    .successor_of_node(node)
+                    .expect("A node with a right child has a successor");
+                self.swap_data(node, succ);
+                self.remove_node(succ, rebalance)
+            }
+            (Some(lchild), None) => self.replace_node(node, Some(lchild), rebalance),
+            (None, Some(rchild)) => self.replace_node(node, Some(rchild), rebalance),
+        }
+    }
+
+    // Removes a node found by one of the lookups and hands back its data.
+    fn take_node(&mut self, node: NodePtr<K, S, A>) -> K {
+        self.count -= 1;
+        self.remove_node(node, true)
+    }
+
+    fn replace_node(&mut self, node: NodePtr<K, S, A>, r: Link<K, S, A>, rebalance: bool) -> K {
+        let parent = self.unlink_node(node, r);
+
+        if rebalance {
+            self.update_path(parent);
+            self.trace_operation("remove", node);
+            B::deleted(self, r, parent);
+        }
+        self.free_node(node).data
+    }
+
+    fn balance_inserted(&mut self, mut node: NodePtr<K, S, A>) {
+        while let Some(parent) = self.node(node).parent {
+            let node_rank = self.node(node).rank;
+            let parent_rank = self.node(parent).rank;
+            if parent_rank - node_rank == 1 {
+                break;
+            }
+
+            let sibling_rank = self.get_node_sibling_rank(Some(node), parent);
+            if parent_rank - sibling_rank == 1 {
+                self.rebalance_step(Case::InsertPromote);
+                self.promote(parent);
+                node = parent;
+                continue;
+            }
+
+            if parent_rank - sibling_rank == 2 {
+                let node_left_rank = self.rank(self.node(node).left);
+                let node_right_rank = self.rank(self.node(node).right);
+
+                if self.node(parent).right == Some(node) {
+                    // Rotate left
+                    if node_rank - node_right_rank == 1 {
+                        self.rebalance_step(Case::InsertRotate);
+                        self.rotate_left(parent);
+                        self.demote(parent);
+                    } else if node_rank - node_left_rank == 1 {
+                        let t = self.node(node).left.expect("A 1-child exists");
+                        self.rebalance_step(Case::InsertDoubleRotate);
+                        self.rotate_right(node);
+                        self.demote(node);
+                        self.promote(t);
+
+                        self.rotate_left(parent);
+                        self.demote(parent);
+                    }
+                } else {
+                    // Rotate right
+                    if node_rank - node_left_rank == 1 {
+                        self.rebalance_step(Case::InsertRotate);
+                        self.rotate_right(parent);
+                        self.demote(parent);
+                    } else if node_rank - node_right_rank == 1 {
+                        let t = self.node(node).right.expect("A 1-child exists");
+                        self.rebalance_step(Case::InsertDoubleRotate);
+                        self.rotate_left(node);
+                        self.demote(node);
+                        self.promote(t);
+
+                        self.rotate_right(parent);
+                        self.demote(parent);
+                    }
+                }
+            }
+            break;
+        }
+    }
+
+    fn balance_deleted(&mut self, mut node: Link<K, S, A>, mut parent: Link<K, S, A>) {
+        // Rebalancing stops once it reaches the root
+        while let Some(p) = parent {
+            let node_rank = self.rank(node);
+            let parent_rank = self.node(p).rank;
+
+            if parent_rank - node_rank == 1 {
+                break;
+            }
+
+            if self.node(p).left.is_none() && self.node(p).right.is_none() {
+                // A leaf must have rank 1, the parent lost its last child as a 2,2 leaf
+                self.rebalance_step(Case::DeleteLeaf);
+                self.demote(p);
+
+                node = Some(p);
+                parent = self.node(p).parent;
+                continue;
+            }
+
+            if parent_rank - node_rank != 3 {
+                break;
+            }
+
+            let sibling_rank = self.get_node_sibling_rank(node, p);
+            if parent_rank - sibling_rank == 2 {
+                self.rebalance_step(Case::DeleteDemote);
+                self.demote(p);
+
+                node = Some(p);
+                parent = self.node(p).parent;
+                continue;
+            }
+
+            if parent_rank - sibling_rank != 1 {
+                break;
+            }
+
+            let sibling_left_rank = self.get_node_sibling_left_rank(node, p);
+            let sibling_right_rank = self.get_node_sibling_right_rank(node, p);
+            let s = self
+                .get_node_sibling(node, p)
+                .expect("A 3-child has a sibling");
+
+            if sibling_rank - sibling_left_rank == 2 && sibling_rank - sibling_right_rank == 2 {
+                self.rebalance_step(Case::DeleteDoubleDemote);
+                self.demote(p);
+                self.demote(s);
+
+                node = Some(p);
+                parent = self.node(p).parent;
+                continue;
+            }
+
+            if self.node(p).right == node {
+                if sibling_rank - sibling_left_rank == 1 {
+                    self.rebalance_step(Case::DeleteRotate);
+                    self.rotate_right(p);
+                    self.demote(p);
+                    self.promote(s);
+                } else if sibling_rank - sibling_right_rank == 1 {
+                    let t = self.node(s).right.expect("A 1-child exists");
+                    self.rebalance_step(Case::DeleteDoubleRotate);
+                    self.rotate_left(s);
+                    self.demote(s);
+                    self.promote(t);
+
+                    self.rotate_right(p);
+                    self.demote(p);
+                    self.demote(p);
+                    self.promote(t);
+                } else {
+                    unreachable!("Inbalanced tree state");
+                }
+            } else {
+                // Rotate left
+                if sibling_rank - sibling_right_rank == 1 {
+                    self.rebalance_step(Case::DeleteRotate);
+                    self.rotate_left(p);
+                    self.demote(p);
+                    self.promote(s);
+                } else if sibling_rank - sibling_left_rank == 1 {
+                    let t = self.node(s).left.expect("A 1-child exists");
+                    self.rebalance_step(Case::DeleteDoubleRotate);
+                    self.rotate_right(s);
+                    self.demote(s);
+                    self.promote(t);
+
+                    self.rotate_left(p);
+                    self.demote(p);
+                    self.demote(p);
+                    self.promote(t);
+                } else {
+                    unreachable!("Inbalanced tree state");
+                }
+            }
+            self.check_after_rotation(p);
+            break;
+        }
+    }
+
+    fn rotate_left(&mut self, x: NodePtr<K, S, A>) {
+        let y = self
+            .node(x)
+            .right
+            .expect("Rotating left needs a right child");
+        let inner = self.node(y).left;
+        self.node_mut(x).right = inner;
+
+        if let Some(inner) = inner {
+            self.node_mut(inner).parent = Some(x);
+        }
+
+        let parent = self.node(x).parent;
+        self.node_mut(y).parent = parent;
+        match parent {
+            None => self.root = Some(y),
+            Some(parent) if self.node(parent).left == Some(x) => {
+                self.node_mut(parent).left = Some(y)
+            }
+            Some(parent) => self.node_mut(parent).right = Some(y),
+        }
+
+        self.node_mut(y).left = Some(x);
+        self.node_mut(x).parent = Some(y);
+
+        if let Some(observer) = self.observer.get_mut() {
+            let (x_ref, y_ref) = (A::node(&self.store, x), A::node(&self.store, y));
+            observer.rotate(Side::Left, &x_ref.data, &y_ref.data);
+        }
+
+        self.update_node(x);
+        self.update_node(y);
+        self.trace_rotation(Side::Left, x);
+    }
+
+    fn rotate_right(&mut self, x: NodePtr<K, S, A>) {
+        let y = self
+            .node(x)
+            .left
+            .expect("Rotating right needs a left child");
+        let inner = self.node(y).right;
+        self.node_mut(x).left = inner;
+
+        if let Some(inner) = inner {
+            self.node_mut(inner).parent = Some(x);
+        }
+
+        let parent = self.node(x).parent;
+        self.node_mut(y).parent = parent;
+        match parent {
+            None => self.root = Some(y),
+            Some(parent) if self.node(parent).right == Some(x) => {
+                self.node_mut(parent).right = Some(y)
+            }
+            Some(parent) => self.node_mut(parent).left = Some(y),
+        }
+
+        self.node_mut(y).right = Some(x);
+        self.node_mut(x).parent = Some(y);
+
+        if let Some(observer) = self.observer.get_mut() {
+            let (x_ref, y_ref) = (A::node(&self.store, x), A::node(&self.store, y));
+            observer.rotate(Side::Right, &x_ref.data, &y_ref.data);
+        }
+
+        self.update_node(x);
+        self.update_node(y);
+        self.trace_rotation(Side::Right, x);
+    }
+}
+
+impl<K: Ord, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    // Insert(k)
+    pub fn insert(&mut self, data: K) -> bool {
+        self.insert_data(data).is_some()
+    }
+
+    // Delete(k)
+    pub fn remove<Q>(&mut self, data: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        match self.find_node(self.root, &mut |k: &K| data.cmp(k.borrow())) {
+            Some(node) => {
+                self.take_node(node);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Successor(x)
+    pub fn successor<Q>(&mut self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.find_node(self.root, &mut |k: &K| data.cmp(k.borrow()))?;
+        let nodesucc = self.successor_of_node(node)?;
+        Some(&self.node(nodesucc).data)
+    }
+
+    // Predecessor(x)
+    pub fn predecessor<Q>(&mut self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.find_node(self.root, &mut |k: &K| data.cmp(k.borrow()))?;
+        let nodepred = self.predecessor_of_node(node)?;
+        Some(&self.node(nodepred).data)
+    }
+
+    /// Returns the greatest key less than or equal to `data`.
+    pub fn floor<Q>(&self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.seek_upper(Bound::Included(data))?;
+        Some(&self.node(node).data)
+    }
+
+    /// Returns the least key greater than or equal to `data`.
+    pub fn ceiling<Q>(&self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.seek_lower(Bound::Included(data))?;
+        Some(&self.node(node).data)
+    }
+
+    /// Returns the first key that is not less than `data`, like C++'s
+    /// `std::set::lower_bound`. This is the same key as `ceiling`.
+    pub fn lower_bound<Q>(&self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.ceiling(data)
+    }
+
+    /// Returns the first key that is greater than `data`, like C++'s
+    /// `std::set::upper_bound`.
+    pub fn upper_bound<Q>(&self, data: &Q) -> Option<&K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.seek_lower(Bound::Excluded(data))?;
+        Some(&self.node(node).data)
+    }
+
+    // Search(k)
+    pub fn find<Q>(&mut self, data: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.find_node(self.root, &mut |k: &K| data.cmp(k.borrow()))
+            .is_some()
+    }
+
+    // Inserts `data` and returns its node, or `None` if an equal key is already present.
+    fn insert_data(&mut self, data: K) -> Link<K, S, A> {
+        self.increase_nodes();
+        let node = match self.root {
+            None => {
+                let root = self.alloc_node(Node::new(data, None));
+                self.root = Some(root);
+                self.trace_operation("insert", root);
+                self.root
+            }
+            Some(root) => self.insert_node(root, data),
+        };
+
+        if node.is_some() {
+            self.count += 1;
+        }
+
+        node
+    }
+
+    fn insert_node(&mut self, mut node: NodePtr<K, S, A>, data: K) -> Link<K, S, A> {
+        loop {
+            let node_ref = self.node(node);
+            let (child, left) = match data.cmp(&node_ref.data) {
+                Ordering::Equal => return None,
+                Ordering::Less => (node_ref.left, true),
+                Ordering::Greater => (node_ref.right, false),
+            };
+
+            match child {
+                Some(child) => {
+                    self.increase_nodes();
+                    node = child;
+                }
+                None => {
+                    let inserted = self.alloc_node(Node::new(data, Some(node)));
+                    if left {
+                        self.node_mut(node).left = Some(inserted);
+                    } else {
+                        self.node_mut(node).right = Some(inserted);
+                    }
+                    self.update_path(Some(node));
+                    self.trace_operation("insert", inserted);
+                    B::inserted(self, inserted);
+                    return Some(inserted);
+                }
+            }
+        }
+    }
+}
+
+impl<K, S, A: Storage, B> Drop for Tree<K, S, A, B> {
+    // Frees all nodes in one pass, nothing needs rebalancing anymore
+    fn drop(&mut self) {
+        A::clear(&mut self.store, self.root.take());
+    }
+}
+
+// Navigation and rank helpers
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    // Rank of a node, a missing node has rank 0
+    fn rank(&self, node: Link<K, S, A>) -> i32 {
+        node.map_or(0, |node| self.node(node).rank)
+    }
+
+    fn get_node_sibling(&self, node: Link<K, S, A>, parent: NodePtr<K, S, A>) -> Link<K, S, A> {
+        let parent_ref = self.node(parent);
+
+        if parent_ref.left.is_none() {
+            return parent_ref.right;
+        }
+
+        if parent_ref.right.is_none() {
+            return parent_ref.left;
+        }
+
+        if node.is_none() {
+            unreachable!("Node cannot be null");
+        }
+
+        if parent_ref.left == node {
+            parent_ref.right
+        } else {
+            parent_ref.left
+        }
+    }
+
+    fn get_node_sibling_left_rank(&self, node: Link<K, S, A>, parent: NodePtr<K, S, A>) -> i32 {
+        let sibling = self
+            .get_node_sibling(node, parent)
+            .expect("Sibling cannot be null");
+        self.rank(self.node(sibling).left)
+    }
+
+    fn get_node_sibling_right_rank(&self, node: Link<K, S, A>, parent: NodePtr<K, S, A>) -> i32 {
+        let sibling = self
+            .get_node_sibling(node, parent)
+            .expect("Sibling cannot be null");
+        self.rank(self.node(sibling).right)
+    }
+
+    fn get_node_sibling_rank(&self, node: Link<K, S, A>, parent: NodePtr<K, S, A>) -> i32 {
+        let parent_ref = self.node(parent);
+        if parent_ref.left == node {
+            self.rank(parent_ref.right)
+        } else {
+            self.rank(parent_ref.left)
+        }
+    }
+
+    fn check_after_rotation(&mut self, parent: NodePtr<K, S, A>) {
+        let parent_ref = self.node(parent);
+        let parent_left_rank = self.rank(parent_ref.left);
+        let parent_right_rank = self.rank(parent_ref.right);
+
+        if parent_ref.rank - parent_left_rank == 2 && parent_ref.rank - parent_right_rank == 2 {
+            self.demote(parent);
+        }
+    }
+
+    #[cfg(feature = "order-statistics")]
+    fn subtree_size(&self, node: Link<K, S, A>) -> usize {
+        node.map_or(0, |node| self.node(node).size)
+    }
+
+    fn promote(&mut self, node: NodePtr<K, S, A>) {
+        self.stats.promotions += 1;
+        self.node_mut(node).rank += 1;
+        if let Some(observer) = self.observer.get_mut() {
+            let node_ref = A::node(&self.store, node);
+            observer.promote(&node_ref.data, node_ref.rank);
+        }
+        self.trace_rank("promote", node);
+    }
+
+    fn demote(&mut self, node: NodePtr<K, S, A>) {
+        self.stats.demotions += 1;
+        self.node_mut(node).rank -= 1;
+        if let Some(observer) = self.observer.get_mut() {
+            let node_ref = A::node(&self.store, node);
+            observer.demote(&node_ref.data, node_ref.rank);
+        }
+        self.trace_rank("demote", node);
+    }
+
+    fn leftmost_child(&self, mut node: NodePtr<K, S, A>) -> NodePtr<K, S, A> {
+        while let Some(left) = self.node(node).left {
+            node = left;
+        }
+        node
+    }
+
+    fn rightmost_child(&self, mut node: NodePtr<K, S, A>) -> NodePtr<K, S, A> {
+        while let Some(right) = self.node(node).right {
+            node = right;
+        }
+        node
+    }
+
+    fn successor_of_node(&self, node: NodePtr<K, S, A>) -> Link<K, S, A> {
+        match self.node(node).right {
+            Some(right) => Some(self.leftmost_child(right)),
+            None => self.parent_with_left(node),
+        }
+    }
+
+    fn predecessor_of_node(&self, node: NodePtr<K, S, A>) -> Link<K, S, A> {
+        match self.node(node).left {
+            Some(left) => Some(self.rightmost_child(left)),
+            None => self.parent_with_right(node),
+        }
+    }
+
+    // First ancestor that has the subtree of `node` on its left
+    fn parent_with_left(&self, mut node: NodePtr<K, S, A>) -> Link<K, S, A> {
+        while let Some(parent) = self.node(node).parent {
+            if self.node(parent).left == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+
+    // First ancestor that has the subtree of `node` on its right
+    fn parent_with_right(&self, mut node: NodePtr<K, S, A>) -> Link<K, S, A> {
+        while let Some(parent) = self.node(node).parent {
+            if self.node(parent).right == Some(node) {
+                return Some(parent);
+            }
+            node = parent;
+        }
+        None
+    }
+}
+
+impl<K, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    // Recomputes the subtree size and summary of `node` from its children
+    fn update_node(&mut self, node: NodePtr<K, S, A>) {
+        #[cfg(feature = "order-statistics")]
+        {
+            let node_ref = self.node(node);
+            let size = self.subtree_size(node_ref.left) + self.subtree_size(node_ref.right) + 1;
+            self.node_mut(node).size = size;
+        }
+
+        let node_ref = self.node(node);
+        let empty = S::empty();
+        let left = node_ref
+            .left
+            .map_or(&empty, |left| &self.node(left).summary);
+        let right = node_ref
+            .right
+            .map_or(&empty, |right| &self.node(right).summary);
+        let summary = S::combine(left, &node_ref.data, right);
+        self.node_mut(node).summary = summary;
+    }
+
+    // Recomputes the subtree sizes and summaries from `node` up to the root
+    fn update_path(&mut self, mut node: Link<K, S, A>) {
+        while let Some(current) = node {
+            self.update_node(current);
+            node = self.node(current).parent;
+        }
+    }
+}
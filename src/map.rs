@@ -0,0 +1,237 @@
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+
+use crate::node::{Link, NodePtr};
+use crate::{Boxed, Tree};
+
+/// An ordered map stored in a WAVL tree.
+///
+/// Entries are kept in a `Tree` ordered by key only, so every insertion and
+/// removal goes through the same rebalancing as the set.
+pub struct WavlMap<K, V> {
+    tree: Tree<MapEntry<K, V>>,
+}
+
+struct MapEntry<K, V> {
+    key: K,
+    value: V,
+}
+
+impl<K: Ord, V> PartialEq for MapEntry<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for MapEntry<K, V> {}
+
+impl<K: Ord, V> PartialOrd for MapEntry<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for MapEntry<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<K, V> WavlMap<K, V> {
+    pub fn new() -> Self {
+        Self { tree: Tree::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.node_count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: Ord, V> WavlMap<K, V> {
+    /// Inserts a key/value pair, returning the previous value if the key was present.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+                None
+            }
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.lookup(key)?;
+        Some(&self.tree.node(node).data.value)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.find(key)?;
+        Some(&mut self.tree.node_mut(node).data.value)
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.lookup(key).is_some()
+    }
+
+    /// Removes a key from the map, returning its value if it was present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let node = self.find(key)?;
+        Some(self.tree.take_node(node).value)
+    }
+
+    /// Gets the entry for `key` for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.find(&key) {
+            Some(node) => Entry::Occupied(OccupiedEntry { map: self, node }),
+            None => Entry::Vacant(VacantEntry { map: self, key }),
+        }
+    }
+
+    fn find<Q>(&mut self, key: &Q) -> Link<MapEntry<K, V>, (), Boxed>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let root = self.tree.root;
+        self.tree.find_node(root, &mut |entry: &MapEntry<K, V>| {
+            key.cmp(entry.key.borrow())
+        })
+    }
+
+    // Shared lookups leave the tree statistics alone
+    fn lookup<Q>(&self, key: &Q) -> Link<MapEntry<K, V>, (), Boxed>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.tree
+            .lookup_node(&mut |entry: &MapEntry<K, V>| key.cmp(entry.key.borrow()))
+    }
+}
+
+impl<K, V> Default for WavlMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A view into a single entry of a `WavlMap`, which may be vacant or occupied.
+pub enum Entry<'a, K, V> {
+    Vacant(VacantEntry<'a, K, V>),
+    Occupied(OccupiedEntry<'a, K, V>),
+}
+
+pub struct VacantEntry<'a, K, V> {
+    map: &'a mut WavlMap<K, V>,
+    key: K,
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    map: &'a mut WavlMap<K, V>,
+    node: NodePtr<MapEntry<K, V>, (), Boxed>,
+}
+
+impl<'a, K: Ord, V> Entry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K: Ord, V: Default> Entry<'a, K, V> {
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K: Ord, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        let tree = &mut self.map.tree;
+        let node = tree
+            .insert_data(MapEntry {
+                key: self.key,
+                value,
+            })
+            .expect("A vacant entry has no equal key");
+        &mut tree.node_mut(node).data.value
+    }
+}
+
+impl<'a, K: Ord, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        &self.map.tree.node(self.node).data.key
+    }
+
+    pub fn get(&self) -> &V {
+        &self.map.tree.node(self.node).data.value
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.map.tree.node_mut(self.node).data.value
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.map.tree.node_mut(self.node).data.value
+    }
+
+    /// Replaces the value of the entry and returns the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map and returns its value.
+    pub fn remove(self) -> V {
+        self.map.tree.take_node(self.node).value
+    }
+}
@@ -0,0 +1,71 @@
+use crate::storage::sealed::Sealed;
+use crate::storage::{Boxed, Storage};
+use crate::summary::Summary;
+use crate::{Balance, Tree};
+
+// Public only to appear in the sealed storage trait, the module is private
+pub struct Node<K, S = (), A: Sealed = Boxed> {
+    pub(crate) data: K,
+    pub(crate) rank: i32,
+    // Number of nodes in the subtree rooted here
+    #[cfg(feature = "order-statistics")]
+    pub(crate) size: usize,
+    // Summary of the keys in the subtree rooted here
+    pub(crate) summary: S,
+
+    pub(crate) left: Link<K, S, A>,
+    pub(crate) right: Link<K, S, A>,
+    pub(crate) parent: Link<K, S, A>,
+}
+
+// A handle to a node, only meaningful to the tree that allocated it
+pub(crate) type NodePtr<K, S, A> = <A as Sealed>::Ptr<K, S>;
+
+pub(crate) type Link<K, S, A> = Option<NodePtr<K, S, A>>;
+
+impl<K, S: Summary<K>, A: Sealed> Node<K, S, A> {
+    pub(crate) fn new(data: K, parent: Link<K, S, A>) -> Self {
+        let summary = S::combine(&S::empty(), &data, &S::empty());
+        Self {
+            data,
+            summary,
+            rank: 1,
+            #[cfg(feature = "order-statistics")]
+            size: 1,
+            left: None,
+            right: None,
+            parent,
+        }
+    }
+}
+
+// All node access of the tree goes through its storage
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    pub(crate) fn node(&self, node: NodePtr<K, S, A>) -> &Node<K, S, A> {
+        A::node(&self.store, node)
+    }
+
+    pub(crate) fn node_mut(&mut self, node: NodePtr<K, S, A>) -> &mut Node<K, S, A> {
+        A::node_mut(&mut self.store, node)
+    }
+
+    pub(crate) fn alloc_node(&mut self, node: Node<K, S, A>) -> NodePtr<K, S, A> {
+        let node = A::alloc(&mut self.store, node);
+        if let Some(observer) = self.observer.get_mut() {
+            observer.alloc(&A::node(&self.store, node).data);
+        }
+        node
+    }
+
+    // The node must already be unlinked from the tree
+    pub(crate) fn free_node(&mut self, node: NodePtr<K, S, A>) -> Node<K, S, A> {
+        if let Some(observer) = self.observer.get_mut() {
+            observer.free(&A::node(&self.store, node).data);
+        }
+        A::free(&mut self.store, node)
+    }
+
+    pub(crate) fn swap_data(&mut self, a: NodePtr<K, S, A>, b: NodePtr<K, S, A>) {
+        A::swap_data(&mut self.store, a, b)
+    }
+}
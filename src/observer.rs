@@ -0,0 +1,71 @@
+use crate::{Case, Side};
+
+/// Receives the structural changes of a `Tree` as they happen.
+///
+/// Install one with `Tree::set_observer`. Every method does nothing by
+/// default, so an observer only implements the events it cares about. The
+/// keys are borrowed from the tree for the duration of the call.
+///
+/// Rebalancing calls `rebalance` first, then the rotations, promotions and
+/// demotions of that step. Dropping the tree frees its nodes without events.
+pub trait Observer<K> {
+    /// A rotation towards `side` at `node`: its child `child` on the other
+    /// side takes its place and `node` moves down to `side`.
+    fn rotate(&mut self, side: Side, node: &K, child: &K) {
+        let _ = (side, node, child);
+    }
+
+    /// `key` was promoted to `rank`.
+    fn promote(&mut self, key: &K, rank: i32) {
+        let _ = (key, rank);
+    }
+
+    /// `key` was demoted to `rank`.
+    fn demote(&mut self, key: &K, rank: i32) {
+        let _ = (key, rank);
+    }
+
+    /// A node holding `key` was allocated.
+    fn alloc(&mut self, key: &K) {
+        let _ = key;
+    }
+
+    /// The node holding `key` is about to be freed.
+    fn free(&mut self, key: &K) {
+        let _ = key;
+    }
+
+    /// The rebalancing takes the step `case`.
+    fn rebalance(&mut self, case: Case) {
+        let _ = case;
+    }
+}
+
+// Holds the observer of a tree. Only `&mut Tree` reaches it, a shared tree
+// never touches the observer, so the tree stays `Sync` whether the observer is
+// or not.
+pub(crate) struct ObserverSlot<K>(Option<Box<dyn Observer<K> + Send>>);
+
+// SAFETY: `&ObserverSlot` gives no access to the observer, every method takes
+// `&mut self`.
+unsafe impl<K> Sync for ObserverSlot<K> {}
+
+impl<K> Default for ObserverSlot<K> {
+    fn default() -> Self {
+        ObserverSlot(None)
+    }
+}
+
+impl<K> ObserverSlot<K> {
+    pub(crate) fn set(&mut self, observer: Box<dyn Observer<K> + Send>) {
+        self.0 = Some(observer);
+    }
+
+    pub(crate) fn take(&mut self) -> Option<Box<dyn Observer<K> + Send>> {
+        self.0.take()
+    }
+
+    pub(crate) fn get_mut(&mut self) -> Option<&mut (dyn Observer<K> + Send + 'static)> {
+        self.0.as_deref_mut()
+    }
+}
@@ -0,0 +1,47 @@
+use std::borrow::Borrow;
+
+use crate::{Balance, Storage, Tree};
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Returns the `i`-th smallest key, counting from zero.
+    ///
+    /// Not to be confused with the WAVL rank of a node, this walks down the
+    /// subtree sizes kept in every node and takes O(log n).
+    pub fn select(&self, mut i: usize) -> Option<&K> {
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            let left_size = self.subtree_size(node_ref.left);
+            if i < left_size {
+                node = node_ref.left;
+            } else if i == left_size {
+                return Some(&node_ref.data);
+            } else {
+                i -= left_size + 1;
+                node = node_ref.right;
+            }
+        }
+        None
+    }
+
+    /// Returns the number of keys smaller than `key`, whether `key` is in the
+    /// tree or not. For a present key this is its index in sorted order.
+    pub fn rank_of<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let mut below = 0;
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            if key <= node_ref.data.borrow() {
+                node = node_ref.left;
+            } else {
+                below += self.subtree_size(node_ref.left) + 1;
+                node = node_ref.right;
+            }
+        }
+        below
+    }
+}
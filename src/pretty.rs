@@ -0,0 +1,113 @@
+use std::fmt::{self, Debug, Display};
+
+use crate::{Balance, Side, Storage, Tree};
+
+/// Options of `Tree::pretty`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrettyOptions {
+    /// Levels to draw, the root is level 1. Cut off subtrees are drawn as
+    /// `...`.
+    pub max_depth: Option<usize>,
+    /// Draws the branches with `+--`, `` `-- `` and `|` instead of box drawing
+    /// characters, for terminals without Unicode.
+    pub ascii: bool,
+}
+
+/// A tree drawn top-down for the terminal, made by `Tree::pretty`.
+///
+/// Every line holds a key with its rank and, below the root, its side and
+/// rank difference: `L 3 (rank 1, diff 2)`.
+pub struct Pretty<'a, K, S, A: Storage, B> {
+    tree: &'a Tree<K, S, A, B>,
+    options: PrettyOptions,
+}
+
+impl<K: Display, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Draws the tree in text, print it with `{}`. Unlike `write_dot` this
+    /// needs no Graphviz.
+    pub fn pretty(&self, options: PrettyOptions) -> Pretty<'_, K, S, A, B> {
+        Pretty {
+            tree: self,
+            options,
+        }
+    }
+}
+
+impl<K: Display, S, A: Storage, B: Balance> Display for Pretty<'_, K, S, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.tree.write_pretty(f, &self.options, Display::fmt)
+    }
+}
+
+// The whole tree, with the keys in their `Debug` form
+impl<K: Debug, S, A: Storage, B: Balance> Debug for Tree<K, S, A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write_pretty(f, &PrettyOptions::default(), Debug::fmt)
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    fn write_pretty(
+        &self,
+        f: &mut fmt::Formatter,
+        options: &PrettyOptions,
+        key: fn(&K, &mut fmt::Formatter) -> fmt::Result,
+    ) -> fmt::Result {
+        let Some(root) = self.root else {
+            return writeln!(f, "(empty)");
+        };
+        let (branch, last, bar, blank) = if options.ascii {
+            ("+-- ", "`-- ", "|   ", "    ")
+        } else {
+            ("├── ", "└── ", "│   ", "    ")
+        };
+
+        // Preorder with an explicit stack, each entry carries the start of its
+        // own line and the start of its children's lines
+        let mut stack = vec![(root, String::new(), String::new(), None, 1)];
+        while let Some((node, line, below, side, depth)) = stack.pop() {
+            let node_ref = self.node(node);
+            f.write_str(&line)?;
+            match side {
+                Some(Side::Left) => f.write_str("L ")?,
+                Some(Side::Right) => f.write_str("R ")?,
+                None => {}
+            }
+            key(&node_ref.data, f)?;
+            match node_ref.parent {
+                Some(parent) => writeln!(
+                    f,
+                    " (rank {}, diff {})",
+                    node_ref.rank,
+                    self.node(parent).rank - node_ref.rank
+                )?,
+                None => writeln!(f, " (rank {})", node_ref.rank)?,
+            }
+
+            let children: Vec<_> = [(node_ref.left, Side::Left), (node_ref.right, Side::Right)]
+                .into_iter()
+                .filter_map(|(child, side)| child.map(|child| (child, side)))
+                .collect();
+            if children.is_empty() {
+                continue;
+            }
+            if options.max_depth.is_some_and(|max| depth >= max) {
+                writeln!(f, "{}{}...", below, last)?;
+                continue;
+            }
+            // The left child comes out first
+            let count = children.len();
+            for (i, (child, side)) in children.into_iter().enumerate().rev() {
+                let is_last = i + 1 == count;
+                stack.push((
+                    child,
+                    format!("{}{}", below, if is_last { last } else { branch }),
+                    format!("{}{}", below, if is_last { blank } else { bar }),
+                    Some(side),
+                    depth + 1,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
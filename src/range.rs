@@ -0,0 +1,138 @@
+use std::borrow::Borrow;
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+use crate::node::{Link, NodePtr};
+use crate::{Balance, Boxed, Storage, Tree, Wavl};
+
+/// An in-order iterator over a sub-range of the keys of a `Tree`.
+pub struct Range<'a, K, S = (), A: Storage = Boxed, B = Wavl> {
+    tree: &'a Tree<K, S, A, B>,
+    front: Link<K, S, A>,
+    back: Link<K, S, A>,
+}
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Iterates over the keys within `range` in ascending order.
+    ///
+    /// Each end is found with its own descent from the root, O(log n) in
+    /// all, the keys in between are then walked in order. A range whose start
+    /// lies after its end yields nothing.
+    pub fn range<Q, R>(&self, range: R) -> Range<'_, K, S, A, B>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let front = self.seek_lower(range.start_bound());
+        let back = self.seek_upper(range.end_bound());
+
+        let (front, back) = match (front, back) {
+            (Some(first), Some(last))
+                if self.node(first).data.borrow() <= self.node(last).data.borrow() =>
+            {
+                (front, back)
+            }
+            _ => (None, None),
+        };
+
+        Range {
+            tree: self,
+            front,
+            back,
+        }
+    }
+
+    // First node whose key lies above the lower bound
+    pub(crate) fn seek_lower<Q>(&self, bound: Bound<&Q>) -> Link<K, S, A>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (key, inclusive) = match bound {
+            Bound::Unbounded => return self.root.map(|root| self.leftmost_child(root)),
+            Bound::Included(key) => (key, true),
+            Bound::Excluded(key) => (key, false),
+        };
+
+        let mut found = None;
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            let node_key = node_ref.data.borrow();
+            if node_key > key || (inclusive && node_key == key) {
+                found = node;
+                node = node_ref.left;
+            } else {
+                node = node_ref.right;
+            }
+        }
+        found
+    }
+
+    // Last node whose key lies below the upper bound
+    pub(crate) fn seek_upper<Q>(&self, bound: Bound<&Q>) -> Link<K, S, A>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let (key, inclusive) = match bound {
+            Bound::Unbounded => return self.root.map(|root| self.rightmost_child(root)),
+            Bound::Included(key) => (key, true),
+            Bound::Excluded(key) => (key, false),
+        };
+
+        let mut found = None;
+        let mut node = self.root;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            let node_key = node_ref.data.borrow();
+            if node_key < key || (inclusive && node_key == key) {
+                found = node;
+                node = node_ref.right;
+            } else {
+                node = node_ref.left;
+            }
+        }
+        found
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> Range<'a, K, S, A, B> {
+    // Hands out `node` and ends the range once both ends have met
+    fn take(&mut self, node: NodePtr<K, S, A>, next: Link<K, S, A>, back: bool) -> Option<&'a K> {
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else if back {
+            self.back = next;
+        } else {
+            self.front = next;
+        }
+        Some(&self.tree.node(node).data)
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> Iterator for Range<'a, K, S, A, B> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<&'a K> {
+        let node = self.front?;
+        self.take(node, self.tree.successor_of_node(node), false)
+    }
+}
+
+impl<'a, K, S, A: Storage, B: Balance> DoubleEndedIterator for Range<'a, K, S, A, B> {
+    fn next_back(&mut self) -> Option<&'a K> {
+        let node = self.back?;
+        self.take(node, self.tree.predecessor_of_node(node), true)
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> FusedIterator for Range<'_, K, S, A, B> {}
+
+impl<K, S, A: Storage, B: Balance> Clone for Range<'_, K, S, A, B> {
+    fn clone(&self) -> Self {
+        Range { ..*self }
+    }
+}
@@ -0,0 +1,125 @@
+// Red-black trees in rank-balanced form: the rank of a node is its black
+// height. Every rank difference is 0 or 1, a missing child is a 1-child and no
+// 0-child has a 0-child of its own. A 0-child is a red node, a 1-child a black
+// one, so the colours need no field of their own.
+
+use crate::node::{Link, NodePtr};
+use crate::{Balance, Case, Storage, Summary, Tree};
+
+impl<K, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    // A new leaf has rank 1 and is a 0-child, its parent may be one too
+    pub(crate) fn rb_balance_inserted(&mut self, mut node: NodePtr<K, S, A>) {
+        while let Some(parent) = self.node(node).parent {
+            let rank = self.node(node).rank;
+            if self.node(parent).rank != rank {
+                break;
+            }
+            let grandparent = match self.node(parent).parent {
+                Some(grandparent) if self.node(grandparent).rank == rank => grandparent,
+                // The parent is the root or a 1-child
+                _ => break,
+            };
+
+            let parent_is_left = self.node(grandparent).left == Some(parent);
+            let uncle = if parent_is_left {
+                self.node(grandparent).right
+            } else {
+                self.node(grandparent).left
+            };
+            if self.rank(uncle) == rank {
+                // Both children of the grandparent are 0-children
+                self.rebalance_step(Case::InsertPromoteGrandparent);
+                self.promote(grandparent);
+                node = grandparent;
+                continue;
+            }
+
+            let node_is_left = self.node(parent).left == Some(node);
+            if parent_is_left != node_is_left {
+                self.rebalance_step(Case::InsertDoubleRotate);
+                if node_is_left {
+                    self.rotate_right(parent);
+                } else {
+                    self.rotate_left(parent);
+                }
+            } else {
+                self.rebalance_step(Case::InsertRotate);
+            }
+
+            // The ranks stay, the rotation alone moves the 0-children apart
+            if parent_is_left {
+                self.rotate_right(grandparent);
+            } else {
+                self.rotate_left(grandparent);
+            }
+            break;
+        }
+    }
+
+    // Removing a node can leave `node` a 2-child of `parent`
+    pub(crate) fn rb_balance_deleted(&mut self, node: Link<K, S, A>, mut parent: Link<K, S, A>) {
+        let mut node = node;
+        while let Some(p) = parent {
+            let parent_rank = self.node(p).rank;
+            if parent_rank - self.rank(node) <= 1 {
+                break;
+            }
+
+            let s = self
+                .get_node_sibling(node, p)
+                .expect("A 2-child has a sibling");
+            let sibling_is_right = self.node(p).right == Some(s);
+            let sibling_rank = self.node(s).rank;
+
+            if sibling_rank == parent_rank {
+                // Turn the 0-sibling into the parent, `node` gets a 1-sibling
+                self.rebalance_step(Case::DeleteRotateSibling);
+                if sibling_is_right {
+                    self.rotate_left(p);
+                } else {
+                    self.rotate_right(p);
+                }
+                continue;
+            }
+
+            let (inner, outer) = if sibling_is_right {
+                (self.node(s).left, self.node(s).right)
+            } else {
+                (self.node(s).right, self.node(s).left)
+            };
+
+            if self.rank(outer) == sibling_rank {
+                self.rebalance_step(Case::DeleteRotate);
+                if sibling_is_right {
+                    self.rotate_left(p);
+                } else {
+                    self.rotate_right(p);
+                }
+                self.promote(s);
+                self.demote(p);
+                break;
+            }
+
+            if self.rank(inner) == sibling_rank {
+                let t = inner.expect("A 0-child exists");
+                self.rebalance_step(Case::DeleteDoubleRotate);
+                if sibling_is_right {
+                    self.rotate_right(s);
+                    self.rotate_left(p);
+                } else {
+                    self.rotate_left(s);
+                    self.rotate_right(p);
+                }
+                self.promote(t);
+                self.demote(p);
+                break;
+            }
+
+            // The sibling has two 1-children and can become a 0-child
+            self.rebalance_step(Case::DeleteDemote);
+            self.demote(p);
+            node = Some(p);
+            parent = self.node(p).parent;
+        }
+    }
+}
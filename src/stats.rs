@@ -0,0 +1,109 @@
+use std::fmt;
+
+/// A rebalancing step, named after the rank rule violation it repairs.
+///
+/// Not every policy uses every case. `Wavl` and `Avl` share the insertion
+/// cases, `RedBlack` promotes the grandparent instead of the parent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// The parent of a 0-child is promoted.
+    InsertPromote,
+    /// Red-black: the grandparent of two 0-children in a row is promoted.
+    InsertPromoteGrandparent,
+    /// A single rotation ends the insertion.
+    InsertRotate,
+    /// A double rotation ends the insertion.
+    InsertDoubleRotate,
+    /// WAVL: a parent that became a 2,2 leaf is demoted.
+    DeleteLeaf,
+    /// The parent is demoted: WAVL above a 3-child with a 2-sibling, AVL at
+    /// a 2,2 node, red-black above a 2-child whose sibling has two 1-children.
+    DeleteDemote,
+    /// WAVL: the parent and its 2,2 sibling are demoted together.
+    DeleteDoubleDemote,
+    /// Red-black: a 0-sibling is rotated up so the 2-child gets a 1-sibling.
+    DeleteRotateSibling,
+    /// A single rotation after a deletion.
+    DeleteRotate,
+    /// A double rotation after a deletion.
+    DeleteDoubleRotate,
+}
+
+impl Case {
+    /// Every case, in declaration order.
+    pub const ALL: [Case; 10] = [
+        Case::InsertPromote,
+        Case::InsertPromoteGrandparent,
+        Case::InsertRotate,
+        Case::InsertDoubleRotate,
+        Case::DeleteLeaf,
+        Case::DeleteDemote,
+        Case::DeleteDoubleDemote,
+        Case::DeleteRotateSibling,
+        Case::DeleteRotate,
+        Case::DeleteDoubleRotate,
+    ];
+
+    /// Rotations the step does.
+    pub fn rotations(self) -> usize {
+        match self {
+            Case::InsertRotate | Case::DeleteRotateSibling | Case::DeleteRotate => 1,
+            Case::InsertDoubleRotate | Case::DeleteDoubleRotate => 2,
+            _ => 0,
+        }
+    }
+}
+
+// Names the step of the traced frames
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Case::InsertPromote => "parent promotion",
+            Case::InsertPromoteGrandparent => "grandparent promotion",
+            Case::InsertRotate | Case::DeleteRotate => "rotation",
+            Case::InsertDoubleRotate | Case::DeleteDoubleRotate => "double rotation",
+            Case::DeleteLeaf => "2,2 leaf demotion",
+            Case::DeleteDemote => "parent demotion",
+            Case::DeleteDoubleDemote => "parent and sibling demotion",
+            Case::DeleteRotateSibling => "0-sibling rotation",
+        })
+    }
+}
+
+/// Work counters of a `Tree`, kept since it was created or since the last
+/// `Tree::reset_stats`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats {
+    /// Rank increases by one, a node gaining two ranks counts twice.
+    pub promotions: usize,
+    /// Rank decreases by one.
+    pub demotions: usize,
+    /// Rebalancing steps that rotated once.
+    pub single_rotations: usize,
+    /// Rebalancing steps that rotated twice, each counts once here.
+    pub double_rotations: usize,
+    /// Nodes visited below the root on the way down.
+    pub accessed_nodes: usize,
+    cases: [usize; Case::ALL.len()],
+}
+
+impl Stats {
+    /// Rotations in total, a double rotation counts as two.
+    pub fn rotations(&self) -> usize {
+        self.single_rotations + 2 * self.double_rotations
+    }
+
+    /// How often the rebalancing took the step `case`.
+    pub fn case(&self, case: Case) -> usize {
+        self.cases[case as usize]
+    }
+
+    pub(crate) fn record(&mut self, case: Case) {
+        self.cases[case as usize] += 1;
+        match case.rotations() {
+            1 => self.single_rotations += 1,
+            2 => self.double_rotations += 1,
+            _ => {}
+        }
+    }
+}
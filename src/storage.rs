@@ -0,0 +1,295 @@
+use std::fmt;
+use std::num::NonZeroU32;
+use std::ptr::NonNull;
+
+use crate::node::{Link, Node};
+use crate::{Balance, Tree};
+
+/// Where a `Tree` keeps its nodes.
+///
+/// `Boxed` gives every node its own heap allocation, `Arena` keeps them all
+/// in one growable slab addressed by `u32` indices. The trait is sealed, the
+/// two layouts are the only ones.
+pub trait Storage: sealed::Sealed {}
+
+/// Every node in its own `Box`, the default.
+pub struct Boxed;
+
+/// All nodes in one slab, linked by `u32` indices.
+///
+/// Removed slots go on a free list and are reused by later insertions, and
+/// dropping the tree frees the slab in a single pass. A tree holds at most
+/// `u32::MAX - 1` keys in this layout.
+///
+/// Each tree has a slab of its own, so `Tree::join`, `Tree::append` and
+/// `Tree::split_off` move the nodes that change trees one by one and take
+/// O(n) here instead of O(log n).
+pub struct Arena;
+
+impl Storage for Boxed {}
+
+impl Storage for Arena {}
+
+pub(crate) mod sealed {
+    use std::fmt;
+
+    use crate::node::{Link, Node};
+
+    pub trait Sealed: Sized {
+        type Ptr<K, S>: Copy + Eq + fmt::Debug;
+        type Store<K, S>: Default;
+
+        fn node<K, S>(store: &Self::Store<K, S>, node: Self::Ptr<K, S>) -> &Node<K, S, Self>;
+
+        fn node_mut<K, S>(
+            store: &mut Self::Store<K, S>,
+            node: Self::Ptr<K, S>,
+        ) -> &mut Node<K, S, Self>;
+
+        fn alloc<K, S>(store: &mut Self::Store<K, S>, node: Node<K, S, Self>) -> Self::Ptr<K, S>;
+
+        // The node must already be unlinked from the tree
+        fn free<K, S>(store: &mut Self::Store<K, S>, node: Self::Ptr<K, S>) -> Node<K, S, Self>;
+
+        fn swap_data<K, S>(store: &mut Self::Store<K, S>, a: Self::Ptr<K, S>, b: Self::Ptr<K, S>);
+
+        // Frees every node of the tree rooted at `root`
+        fn clear<K, S>(store: &mut Self::Store<K, S>, root: Link<K, S, Self>);
+
+        // Moves the detached tree rooted at `root` from one store into
+        // another and hands back its new root
+        fn transfer<K, S>(
+            from: &mut Self::Store<K, S>,
+            to: &mut Self::Store<K, S>,
+            root: Link<K, S, Self>,
+        ) -> Link<K, S, Self>;
+    }
+}
+
+// Each node is owned by exactly one tree and only reached through it. A
+// `BoxPtr` is created when the tree allocates a node and is dereferenced only
+// through the tree that owns it, so a borrow of a node never outlives the
+// borrow of its tree.
+pub struct BoxPtr<K, S>(NonNull<Node<K, S, Boxed>>);
+
+impl sealed::Sealed for Boxed {
+    type Ptr<K, S> = BoxPtr<K, S>;
+    type Store<K, S> = ();
+
+    fn node<K, S>(_store: &(), node: BoxPtr<K, S>) -> &Node<K, S, Self> {
+        // The node belongs to the tree and lives as long as it is linked in
+        unsafe { node.0.as_ref() }
+    }
+
+    fn node_mut<K, S>(_store: &mut (), node: BoxPtr<K, S>) -> &mut Node<K, S, Self> {
+        // The tree is borrowed mutably, which rules out any other borrow of the node
+        unsafe { &mut *node.0.as_ptr() }
+    }
+
+    fn alloc<K, S>(_store: &mut (), node: Node<K, S, Self>) -> BoxPtr<K, S> {
+        BoxPtr(NonNull::from(Box::leak(Box::new(node))))
+    }
+
+    fn free<K, S>(_store: &mut (), node: BoxPtr<K, S>) -> Node<K, S, Self> {
+        unsafe { *Box::from_raw(node.0.as_ptr()) }
+    }
+
+    fn swap_data<K, S>(_store: &mut (), a: BoxPtr<K, S>, b: BoxPtr<K, S>) {
+        if a != b {
+            // Two distinct nodes never overlap
+            unsafe {
+                std::ptr::swap(
+                    std::ptr::addr_of_mut!((*a.0.as_ptr()).data),
+                    std::ptr::addr_of_mut!((*b.0.as_ptr()).data),
+                )
+            }
+        }
+    }
+
+    // Frees the nodes bottom-up in one walk
+    fn clear<K, S>(store: &mut (), root: Link<K, S, Self>) {
+        let mut node = root;
+        while let Some(current) = node {
+            let node_ref = Self::node(store, current);
+            if let Some(left) = node_ref.left {
+                Self::node_mut(store, current).left = None;
+                node = Some(left);
+            } else if let Some(right) = node_ref.right {
+                Self::node_mut(store, current).right = None;
+                node = Some(right);
+            } else {
+                node = node_ref.parent;
+                drop(Self::free(store, current));
+            }
+        }
+    }
+
+    // Boxes belong to no store, the nodes stay where they are
+    fn transfer<K, S>(_from: &mut (), _to: &mut (), root: Link<K, S, Self>) -> Link<K, S, Self> {
+        root
+    }
+}
+
+impl<K, S> Clone for BoxPtr<K, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, S> Copy for BoxPtr<K, S> {}
+
+impl<K, S> PartialEq for BoxPtr<K, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<K, S> Eq for BoxPtr<K, S> {}
+
+impl<K, S> fmt::Debug for BoxPtr<K, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// A `BoxPtr` owns its node like a `Box` does
+unsafe impl<K: Send, S: Send> Send for BoxPtr<K, S> {}
+unsafe impl<K: Sync, S: Sync> Sync for BoxPtr<K, S> {}
+
+// Slot index plus one, so that a `Link` stays four bytes
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ArenaIndex(NonZeroU32);
+
+pub struct ArenaStore<K, S> {
+    slots: Vec<Slot<K, S>>,
+    // Head of the list of vacant slots
+    free: Option<ArenaIndex>,
+}
+
+enum Slot<K, S> {
+    Occupied(Node<K, S, Arena>),
+    Vacant(Option<ArenaIndex>),
+}
+
+impl ArenaIndex {
+    fn slot(self) -> usize {
+        self.0.get() as usize - 1
+    }
+}
+
+impl<K, S> ArenaStore<K, S> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            free: None,
+        }
+    }
+}
+
+impl<K, S> Default for ArenaStore<K, S> {
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl sealed::Sealed for Arena {
+    type Ptr<K, S> = ArenaIndex;
+    type Store<K, S> = ArenaStore<K, S>;
+
+    fn node<K, S>(store: &ArenaStore<K, S>, node: ArenaIndex) -> &Node<K, S, Self> {
+        match &store.slots[node.slot()] {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("Link to a vacant slot"),
+        }
+    }
+
+    fn node_mut<K, S>(store: &mut ArenaStore<K, S>, node: ArenaIndex) -> &mut Node<K, S, Self> {
+        match &mut store.slots[node.slot()] {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("Link to a vacant slot"),
+        }
+    }
+
+    fn alloc<K, S>(store: &mut ArenaStore<K, S>, node: Node<K, S, Self>) -> ArenaIndex {
+        match store.free {
+            Some(index) => {
+                let slot = std::mem::replace(&mut store.slots[index.slot()], Slot::Occupied(node));
+                store.free = match slot {
+                    Slot::Vacant(next) => next,
+                    Slot::Occupied(_) => unreachable!("Occupied slot on the free list"),
+                };
+                index
+            }
+            None => {
+                let index = u32::try_from(store.slots.len() + 1)
+                    .ok()
+                    .and_then(NonZeroU32::new)
+                    .expect("Arena is out of u32 indices");
+                store.slots.push(Slot::Occupied(node));
+                ArenaIndex(index)
+            }
+        }
+    }
+
+    fn free<K, S>(store: &mut ArenaStore<K, S>, node: ArenaIndex) -> Node<K, S, Self> {
+        let slot = std::mem::replace(&mut store.slots[node.slot()], Slot::Vacant(store.free));
+        store.free = Some(node);
+        match slot {
+            Slot::Occupied(node) => node,
+            Slot::Vacant(_) => unreachable!("Freeing a vacant slot"),
+        }
+    }
+
+    fn swap_data<K, S>(store: &mut ArenaStore<K, S>, a: ArenaIndex, b: ArenaIndex) {
+        let (low, high) = (a.slot().min(b.slot()), a.slot().max(b.slot()));
+        if low == high {
+            return;
+        }
+
+        let (head, tail) = store.slots.split_at_mut(high);
+        match (&mut head[low], &mut tail[0]) {
+            (Slot::Occupied(a), Slot::Occupied(b)) => std::mem::swap(&mut a.data, &mut b.data),
+            _ => unreachable!("Link to a vacant slot"),
+        }
+    }
+
+    // The slab goes in one pass, links need no walking
+    fn clear<K, S>(store: &mut ArenaStore<K, S>, _root: Link<K, S, Self>) {
+        store.slots.clear();
+        store.free = None;
+    }
+
+    // Every node moves to a slot of the other slab, one walk over the tree
+    fn transfer<K, S>(
+        from: &mut ArenaStore<K, S>,
+        to: &mut ArenaStore<K, S>,
+        root: Link<K, S, Self>,
+    ) -> Link<K, S, Self> {
+        let mut new_root = None;
+        // Old node, its new parent and whether it is the left child
+        let mut stack: Vec<_> = root.map(|root| (root, None, false)).into_iter().collect();
+        while let Some((old, parent, is_left)) = stack.pop() {
+            let mut node = Self::free(from, old);
+            let (left, right) = (node.left.take(), node.right.take());
+            node.parent = parent;
+            let new = Self::alloc(to, node);
+            match parent {
+                None => new_root = Some(new),
+                Some(parent) if is_left => Self::node_mut(to, parent).left = Some(new),
+                Some(parent) => Self::node_mut(to, parent).right = Some(new),
+            }
+            stack.extend(left.map(|left| (left, Some(new), true)));
+            stack.extend(right.map(|right| (right, Some(new), false)));
+        }
+        new_root
+    }
+}
+
+impl<K, S, B: Balance> Tree<K, S, Arena, B> {
+    /// Creates an empty arena tree with room for `capacity` nodes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut tree = Self::default();
+        tree.store = ArenaStore::with_capacity(capacity);
+        tree
+    }
+}
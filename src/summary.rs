@@ -0,0 +1,110 @@
+use std::borrow::Borrow;
+use std::ops::{Bound, RangeBounds};
+
+use crate::node::Link;
+use crate::{Balance, Storage, Tree};
+
+/// An aggregate over the keys of a subtree, kept in every node.
+///
+/// The tree recomputes the summary of each node whose subtree changes, that
+/// is along the path of an insertion or removal and for the nodes moved by a
+/// rotation. Sums, minimums, maximums or the maximum end of an interval tree
+/// all fit this shape. A tree keeping summaries is created with
+/// `Tree::default()`.
+pub trait Summary<K> {
+    /// The summary of an empty subtree.
+    fn empty() -> Self;
+
+    /// Combines the summaries of the two children with the key of their parent.
+    fn combine(left: &Self, key: &K, right: &Self) -> Self;
+}
+
+// The default, keeps nothing
+impl<K> Summary<K> for () {
+    fn empty() {}
+
+    fn combine(_left: &(), _key: &K, _right: &()) {}
+}
+
+impl<K, S: Summary<K>, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Combines the summaries of all keys within `range`, in key order.
+    ///
+    /// This uses the stored subtree summaries and visits O(log n) nodes.
+    pub fn fold_range<Q, R>(&self, range: R) -> S
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let above_lower = |key: &K| match range.start_bound() {
+            Bound::Included(lower) => key.borrow() >= lower,
+            Bound::Excluded(lower) => key.borrow() > lower,
+            Bound::Unbounded => true,
+        };
+        let below_upper = |key: &K| match range.end_bound() {
+            Bound::Included(upper) => key.borrow() <= upper,
+            Bound::Excluded(upper) => key.borrow() < upper,
+            Bound::Unbounded => true,
+        };
+
+        // Find the topmost node within the range, both bounds are below it
+        let mut split = self.root;
+        while let Some(node) = split {
+            let node_ref = self.node(node);
+            if !above_lower(&node_ref.data) {
+                split = node_ref.right;
+            } else if !below_upper(&node_ref.data) {
+                split = node_ref.left;
+            } else {
+                break;
+            }
+        }
+        let split = match split {
+            Some(split) => self.node(split),
+            None => return S::empty(),
+        };
+        let empty = S::empty();
+
+        // Nodes of the left subtree above the lower bound, each one takes its
+        // whole right subtree along
+        let mut taken = vec![];
+        let mut node = split.left;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            if above_lower(&node_ref.data) {
+                taken.push(node_ref);
+                node = node_ref.left;
+            } else {
+                node = node_ref.right;
+            }
+        }
+        let mut left = S::empty();
+        for node in taken.into_iter().rev() {
+            left = S::combine(&left, &node.data, self.summary_of(node.right, &empty));
+        }
+
+        // And the mirror image for the right subtree
+        let mut taken = vec![];
+        let mut node = split.right;
+        while let Some(current) = node {
+            let node_ref = self.node(current);
+            if below_upper(&node_ref.data) {
+                taken.push(node_ref);
+                node = node_ref.right;
+            } else {
+                node = node_ref.left;
+            }
+        }
+        let mut right = S::empty();
+        for node in taken.into_iter().rev() {
+            right = S::combine(self.summary_of(node.left, &empty), &node.data, &right);
+        }
+
+        S::combine(&left, &split.data, &right)
+    }
+
+    // Summary of the subtree at `node`, or `empty` for a missing one
+    fn summary_of<'a>(&'a self, node: Link<K, S, A>, empty: &'a S) -> &'a S {
+        node.map_or(empty, |node| &self.node(node).summary)
+    }
+}
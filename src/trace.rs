@@ -0,0 +1,216 @@
+use std::fmt::{Display, Write as _};
+
+use crate::node::NodePtr;
+use crate::{Balance, Case, DotOptions, Side, Storage, Tree};
+
+/// Snapshot format of a rebalancing trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    /// Graphviz DOT as written by `Tree::write_dot`, 2,2 nodes highlighted.
+    Dot,
+    /// `{"frame", "label", "step", "root", "nodes"}` with the nodes in
+    /// preorder, each
+    /// `{"key", "rank", "left", "right"}` where the children are indices into
+    /// `nodes` or `null`.
+    Json,
+}
+
+/// The tree after one change made by a traced operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Position in the trace, counting from 0.
+    pub number: usize,
+    /// The change that led to this picture: the operation (`insert 5`,
+    /// `remove 5`) or a single rotation (`left rotation at 5`) or rank
+    /// change (`promote 5 to rank 2`) made while rebalancing.
+    pub label: String,
+    /// The rebalancing step the rotation or rank change belongs to, `None`
+    /// for the frame of the operation itself.
+    pub step: Option<Case>,
+    pub snapshot: String,
+}
+
+type Snapshot<K, S, A, B> = fn(&Tree<K, S, A, B>, TraceFormat, &Frame) -> String;
+
+// The frames so far and how to take the next one
+pub(crate) struct Tracer<K, S, A: Storage, B> {
+    format: TraceFormat,
+    frames: Vec<Frame>,
+    // The rebalancing step under way, the group of the next frames
+    step: Option<Case>,
+    snapshot: Snapshot<K, S, A, B>,
+    describe: fn(&K) -> String,
+}
+
+impl<K: Ord + Display, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Records a frame before rebalancing and after every rotation,
+    /// promotion and demotion of the following insertions and removals, until
+    /// `stop_trace`. The frames of one rebalancing step share its `Case`.
+    pub fn start_trace(&mut self, format: TraceFormat) {
+        self.tracer = Some(Box::new(Tracer {
+            format,
+            frames: vec![],
+            step: None,
+            snapshot: Self::snapshot,
+            describe: |key| key.to_string(),
+        }));
+    }
+
+    fn snapshot(&self, format: TraceFormat, frame: &Frame) -> String {
+        match format {
+            TraceFormat::Dot => {
+                let options = DotOptions {
+                    highlight_two_two: true,
+                    ..DotOptions::default()
+                };
+                let mut out = vec![];
+                self.write_dot(&mut out, &options)
+                    .expect("Writing to a Vec does not fail");
+                let dot = String::from_utf8(out).expect("The DOT output is UTF-8");
+                // A caption for the animation, right below the graph header
+                let (header, body) = dot.split_once('\n').expect("The header is a line");
+                let caption = match frame.step {
+                    Some(step) => format!("{}: {} ({})", frame.number, frame.label, step),
+                    None => format!("{}: {}", frame.number, frame.label),
+                };
+                format!(
+                    "{}\n  label=\"{}\";\n  labelloc=t;\n{}",
+                    header,
+                    caption.replace('"', "\\\""),
+                    body
+                )
+            }
+            TraceFormat::Json => self.snapshot_json(frame),
+        }
+    }
+
+    fn snapshot_json(&self, frame: &Frame) -> String {
+        let mut out = format!(
+            "{{\"frame\": {}, \"label\": \"{}\", \"step\": {}, \"root\": {}, \"nodes\": [",
+            frame.number,
+            escape_json(&frame.label),
+            frame
+                .step
+                .map_or("null".to_string(), |step| format!("\"{}\"", step)),
+            if self.root.is_some() { "0" } else { "null" }
+        );
+
+        // Preorder with an explicit stack, a node's index is known when it is
+        // popped and goes into the left or right slot of its parent
+        let mut nodes = vec![];
+        let mut children: Vec<[Option<usize>; 2]> = vec![];
+        let mut stack: Vec<_> = self
+            .root
+            .map(|root| (root, None::<(usize, usize)>))
+            .into_iter()
+            .collect();
+        while let Some((node, parent)) = stack.pop() {
+            let index = nodes.len();
+            if let Some((parent, slot)) = parent {
+                children[parent][slot] = Some(index);
+            }
+            nodes.push(node);
+            children.push([None, None]);
+            let node_ref = self.node(node);
+            stack.extend(node_ref.right.map(|right| (right, Some((index, 1)))));
+            stack.extend(node_ref.left.map(|left| (left, Some((index, 0)))));
+        }
+
+        let index = |child: Option<usize>| child.map_or("null".to_string(), |i| i.to_string());
+        for (i, (&node, [left, right])) in nodes.iter().zip(&children).enumerate() {
+            let node_ref = self.node(node);
+            if i > 0 {
+                out.push_str(", ");
+            }
+            write!(
+                out,
+                "{{\"key\": \"{}\", \"rank\": {}, \"left\": {}, \"right\": {}}}",
+                escape_json(&node_ref.data.to_string()),
+                node_ref.rank,
+                index(*left),
+                index(*right)
+            )
+            .expect("Writing to a String does not fail");
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+impl<K, S, A: Storage, B: Balance> Tree<K, S, A, B> {
+    /// Ends tracing and hands back the frames in order.
+    pub fn stop_trace(&mut self) -> Vec<Frame> {
+        self.tracer
+            .take()
+            .map_or_else(Vec::new, |tracer| tracer.frames)
+    }
+
+    // A frame of the tree right before rebalancing, `verb` names the operation
+    pub(crate) fn trace_operation(&mut self, verb: &str, node: NodePtr<K, S, A>) {
+        if let Some(tracer) = self.tracer.as_deref_mut() {
+            tracer.step = None;
+        }
+        if let Some(tracer) = &self.tracer {
+            let label = format!("{} {}", verb, (tracer.describe)(&self.node(node).data));
+            self.push_frame(label);
+        }
+    }
+
+    // The following rotations and rank changes belong to `case`
+    pub(crate) fn trace_step(&mut self, case: Case) {
+        if let Some(tracer) = self.tracer.as_deref_mut() {
+            tracer.step = Some(case);
+        }
+    }
+
+    // A frame after rotating towards `side` at `node`
+    pub(crate) fn trace_rotation(&mut self, side: Side, node: NodePtr<K, S, A>) {
+        if let Some(tracer) = &self.tracer {
+            let side = match side {
+                Side::Left => "left",
+                Side::Right => "right",
+            };
+            let key = (tracer.describe)(&self.node(node).data);
+            self.push_frame(format!("{} rotation at {}", side, key));
+        }
+    }
+
+    // A frame after a promotion or demotion of `node`, `verb` tells which
+    pub(crate) fn trace_rank(&mut self, verb: &str, node: NodePtr<K, S, A>) {
+        if let Some(tracer) = &self.tracer {
+            let node_ref = self.node(node);
+            let key = (tracer.describe)(&node_ref.data);
+            self.push_frame(format!("{} {} to rank {}", verb, key, node_ref.rank));
+        }
+    }
+
+    fn push_frame(&mut self, label: String) {
+        // The snapshot reads the tree, the tracer steps aside meanwhile
+        if let Some(mut tracer) = self.tracer.take() {
+            let mut frame = Frame {
+                number: tracer.frames.len(),
+                label,
+                step: tracer.step,
+                snapshot: String::new(),
+            };
+            frame.snapshot = (tracer.snapshot)(self, tracer.format, &frame);
+            tracer.frames.push(frame);
+            self.tracer = Some(tracer);
+        }
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                write!(out, "\\u{:04x}", c as u32).expect("Writing to a String does not fail")
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,48 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Arena, Tree};
+
+const SIZE: u32 = 2000;
+
+#[test]
+fn matches_boxed_layout() {
+    let mut rng = StdRng::seed_from_u64(0x51ab);
+    let mut keys: Vec<u32> = (0..SIZE).collect();
+    keys.shuffle(&mut rng);
+
+    let mut boxed = Tree::new();
+    let mut arena: Tree<u32, (), Arena> = Tree::default();
+    for &key in &keys {
+        assert_eq!(boxed.insert(key), arena.insert(key));
+    }
+    arena.check_invariants().unwrap();
+    assert_eq!(boxed.root_rank(), arena.root_rank());
+    assert_eq!(boxed.stats(), arena.stats());
+
+    keys.shuffle(&mut rng);
+    for &key in &keys[..SIZE as usize / 2] {
+        assert!(boxed.remove(&key));
+        assert!(arena.remove(&key));
+    }
+    arena.check_invariants().unwrap();
+    assert!(boxed.iter().eq(arena.iter()));
+}
+
+#[test]
+fn reuses_removed_slots() {
+    let mut tree: Tree<u32, (), Arena> = Tree::with_capacity(16);
+    for round in 0..50 {
+        for key in 0..16 {
+            assert!(tree.insert(round * 100 + key));
+        }
+        tree.check_invariants().unwrap();
+        for key in 0..16 {
+            assert!(tree.remove(&(round * 100 + key)));
+        }
+        assert_eq!(tree.node_count(), 0);
+    }
+
+    tree.insert(7);
+    assert!(tree.into_iter().eq([7]));
+}
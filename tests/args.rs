@@ -0,0 +1,40 @@
+// Checks the command line handling of the bench example, its modules are
+// included as they are
+
+#[allow(dead_code)]
+#[path = "../examples/bench/args.rs"]
+mod args;
+#[allow(dead_code)]
+#[path = "../examples/bench/keys.rs"]
+mod keys;
+#[allow(dead_code)]
+#[path = "../examples/bench/report.rs"]
+mod report;
+
+use args::Args;
+
+fn parse(args: &str) -> Result<Args, String> {
+    Args::parse(args.split(' ').map(String::from))
+}
+
+#[test]
+fn sums_size_and_ops_once() {
+    let args = parse("--size 1000 --ops 50").unwrap();
+    assert_eq!((args.size, args.ops, args.workload), (1000, 50, 1050));
+    assert_eq!(parse("--size 1000").unwrap().workload, 1200);
+}
+
+#[test]
+fn rejects_size_and_ops_past_usize() {
+    let ops = usize::MAX.to_string();
+    let error = parse(&format!("--size 10 --ops {}", ops)).err().unwrap();
+    assert_eq!(error, format!("--size 10 plus --ops {} is too large", ops));
+}
+
+#[test]
+fn rejects_sizes_past_the_key_support() {
+    let error = parse("--size 3000000000 --ops 0 --keys zipf:2")
+        .err()
+        .unwrap();
+    assert!(error.starts_with("--size 3000000000 is above"), "{}", error);
+}
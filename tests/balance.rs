@@ -0,0 +1,116 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Arena, Avl, Balance, Boxed, RedBlack, Tree, Unbalanced, Wavl};
+
+// Inserts `keys` and removes the first half of them again, checking the rank
+// rules after every step
+fn churn<B: Balance>(keys: &[u32]) -> Tree<u32, (), Boxed, B> {
+    let mut tree = Tree::default();
+    let mut sorted = keys.to_vec();
+    sorted.sort_unstable();
+    for &key in &sorted {
+        assert!(tree.insert(key));
+        tree.check_invariants().unwrap();
+    }
+    for &key in &keys[..keys.len() / 2] {
+        assert!(tree.remove(&key));
+        tree.check_invariants().unwrap();
+    }
+    tree
+}
+
+#[test]
+fn policies_hold_the_same_keys() {
+    let mut rng = StdRng::seed_from_u64(0xba1);
+    let mut keys: Vec<u32> = (0..1000).collect();
+    keys.shuffle(&mut rng);
+
+    let mut wavl: Tree<u32, (), Boxed, Wavl> = Tree::default();
+    let mut plain: Tree<u32, (), Boxed, Unbalanced> = Tree::default();
+    for &key in &keys {
+        assert_eq!(wavl.insert(key), plain.insert(key));
+    }
+    keys.shuffle(&mut rng);
+    for &key in &keys[..500] {
+        assert_eq!(wavl.remove(&key), plain.remove(&key));
+    }
+
+    wavl.check_invariants().unwrap();
+    plain.check_invariants().unwrap();
+    assert!(wavl.iter().eq(plain.iter()));
+    assert!(churn::<Avl>(&keys).iter().eq(plain.iter()));
+    assert!(churn::<RedBlack>(&keys).iter().eq(plain.iter()));
+    assert_eq!(plain.stats().rotations(), 0);
+    assert!(wavl.height() < plain.height());
+}
+
+#[test]
+fn avl_and_red_black_keep_their_rules() {
+    let mut rng = StdRng::seed_from_u64(0xa71);
+    for size in [1, 2, 3, 10, 100, 300] {
+        let mut keys: Vec<u32> = (0..size).collect();
+        keys.shuffle(&mut rng);
+
+        let mut avl: Tree<u32, (), Arena, Avl> = Tree::default();
+        let mut red_black: Tree<u32, (), Arena, RedBlack> = Tree::default();
+        for &key in &keys {
+            avl.insert(key);
+            red_black.insert(key);
+            avl.check_invariants().unwrap();
+            red_black.check_invariants().unwrap();
+        }
+
+        // Heights stay within the AVL and red-black bounds
+        let log = (size as f64 + 1.0).log2();
+        assert!(avl.height() as f64 <= 1.45 * log + 1.0);
+        assert!(red_black.height() as f64 <= 2.0 * log + 1.0);
+
+        keys.shuffle(&mut rng);
+        for &key in &keys {
+            assert!(avl.remove(&key));
+            assert!(red_black.remove(&key));
+            avl.check_invariants().unwrap();
+            red_black.check_invariants().unwrap();
+        }
+        assert_eq!(avl.node_count() + red_black.node_count(), 0);
+    }
+
+    // Sorted insertions drive both through all their cases
+    churn::<Avl>(&(0..500).collect::<Vec<_>>());
+    churn::<RedBlack>(&(0..500).rev().collect::<Vec<_>>());
+}
+
+// Sorted keys turn the unbalanced tree into a list. Every walk over it has to
+// run in constant stack space, so it is built on a thread with a small stack.
+#[test]
+fn unbalanced_sorted_keys_do_not_recurse() {
+    const SIZE: u32 = 5000;
+
+    std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            let mut tree: Tree<u32, (), Arena, Unbalanced> = Tree::default();
+            for key in 0..SIZE {
+                assert!(tree.insert(key));
+            }
+            assert_eq!(tree.height(), SIZE as usize);
+            tree.check_invariants().unwrap();
+
+            assert!(tree.find(&(SIZE - 1)));
+            assert_eq!(tree.successor(&(SIZE - 2)), Some(&(SIZE - 1)));
+            assert_eq!(tree.predecessor(&(SIZE - 1)), Some(&(SIZE - 2)));
+            assert!(tree.iter().rev().copied().eq((0..SIZE).rev()));
+            assert!(tree.remove(&0));
+
+            let boxed: Tree<u32, (), Boxed, Unbalanced> =
+                (0..SIZE).rev().fold(Tree::default(), |mut tree, key| {
+                    tree.insert(key);
+                    tree
+                });
+            assert_eq!(boxed.height(), SIZE as usize);
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+}
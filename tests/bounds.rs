@@ -0,0 +1,50 @@
+use wavl::Tree;
+
+// Timestamps 10, 20, .., 100
+fn tree() -> Tree<u64> {
+    let mut tree = Tree::new();
+    for key in (10..=100).step_by(10) {
+        tree.insert(key);
+    }
+    tree
+}
+
+#[test]
+fn absent_keys() {
+    let tree = tree();
+    assert_eq!(tree.floor(&35), Some(&30));
+    assert_eq!(tree.ceiling(&35), Some(&40));
+    assert_eq!(tree.lower_bound(&35), Some(&40));
+    assert_eq!(tree.upper_bound(&35), Some(&40));
+
+    assert_eq!(tree.floor(&5), None);
+    assert_eq!(tree.ceiling(&5), Some(&10));
+    assert_eq!(tree.floor(&105), Some(&100));
+    assert_eq!(tree.ceiling(&105), None);
+    assert_eq!(tree.upper_bound(&105), None);
+}
+
+#[test]
+fn present_keys() {
+    let tree = tree();
+    assert_eq!(tree.floor(&30), Some(&30));
+    assert_eq!(tree.ceiling(&30), Some(&30));
+    assert_eq!(tree.lower_bound(&30), Some(&30));
+    assert_eq!(tree.upper_bound(&30), Some(&40));
+    assert_eq!(tree.upper_bound(&100), None);
+}
+
+#[test]
+fn matches_linear_scan() {
+    let tree = tree();
+    let keys: Vec<u64> = tree.iter().copied().collect();
+    for probe in 0..=110 {
+        assert_eq!(tree.floor(&probe), keys.iter().rev().find(|k| **k <= probe));
+        assert_eq!(tree.ceiling(&probe), keys.iter().find(|k| **k >= probe));
+        assert_eq!(tree.upper_bound(&probe), keys.iter().find(|k| **k > probe));
+    }
+
+    let empty = Tree::<u64>::new();
+    assert_eq!(empty.floor(&1), None);
+    assert_eq!(empty.ceiling(&1), None);
+}
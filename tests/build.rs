@@ -0,0 +1,79 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Arena, Avl, Balance, Boxed, RedBlack, Stats, Tree, Unbalanced, Wavl};
+
+// Every size builds a valid tree that keeps working afterwards
+fn builds_every_size<B: Balance>() {
+    for size in 0..200u32 {
+        let mut tree: Tree<u32, (), Boxed, B> = Tree::from_sorted_iter(0..size);
+        tree.check_invariants().unwrap();
+        assert_eq!(tree.node_count(), size as usize);
+        assert!(tree.iter().copied().eq(0..size));
+        assert_eq!(*tree.stats(), Stats::default());
+
+        for key in (0..size).step_by(3) {
+            assert!(tree.remove(&key));
+            tree.check_invariants().unwrap();
+        }
+        for key in size..size + 10 {
+            assert!(tree.insert(key));
+            tree.check_invariants().unwrap();
+        }
+    }
+}
+
+#[test]
+fn builds_valid_trees() {
+    builds_every_size::<Wavl>();
+    builds_every_size::<Avl>();
+    builds_every_size::<RedBlack>();
+    builds_every_size::<Unbalanced>();
+
+    let tree: Tree<u32, (), Arena> = Tree::from_sorted_iter(0..1000);
+    tree.check_invariants().unwrap();
+}
+
+#[test]
+fn leaves_have_rank_one_and_height_is_minimal() {
+    let tree: Tree<u32> = Tree::from_sorted_iter(0..1000);
+    // 1000 keys fit in 10 levels
+    assert_eq!(tree.height(), 10);
+    assert_eq!(tree.root_rank(), Some(10));
+
+    let tree: Tree<u32> = Tree::from_sorted_iter(0..2);
+    assert_eq!(tree.inorder(), [(&0, 2), (&1, 1)]);
+}
+
+#[test]
+#[should_panic(expected = "strictly increasing")]
+fn rejects_unsorted_keys() {
+    let _: Tree<u32> = Tree::from_sorted_iter([1, 3, 2]);
+}
+
+#[test]
+#[should_panic(expected = "strictly increasing")]
+fn rejects_duplicate_keys() {
+    let _: Tree<u32> = Tree::from_sorted_iter([1, 2, 2]);
+}
+
+#[test]
+fn collects_sorted_input_without_rebalancing() {
+    let tree: Tree<u32> = [1, 2, 2, 3, 5, 8].into_iter().collect();
+    tree.check_invariants().unwrap();
+    assert_eq!(*tree.stats(), Stats::default());
+    assert!(tree.iter().copied().eq([1, 2, 3, 5, 8]));
+
+    let mut keys: Vec<u32> = (0..500).collect();
+    keys.shuffle(&mut StdRng::seed_from_u64(0xb17d));
+    let mut tree: Tree<u32> = keys.iter().copied().collect();
+    tree.check_invariants().unwrap();
+    assert!(tree.stats().rotations() > 0);
+
+    // Sorted keys past the end are built apart and joined
+    tree.reset_stats();
+    tree.extend(500..600);
+    tree.check_invariants().unwrap();
+    assert!(tree.iter().copied().eq(0..600));
+    assert!(tree.stats().rotations() <= 2);
+}
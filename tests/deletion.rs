@@ -0,0 +1,60 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::Tree;
+
+const MAX_SIZE: i32 = 200;
+
+fn drain(keys: &[i32], order: &[i32]) {
+    let mut tree = Tree::new();
+    for &key in keys {
+        assert!(tree.insert(key));
+    }
+    tree.check_invariants().unwrap();
+
+    for key in order {
+        assert!(tree.remove(key));
+        assert!(!tree.find(key));
+        tree.check_invariants().unwrap();
+    }
+
+    assert_eq!(tree.node_count(), 0);
+    assert_eq!(tree.root_rank(), None);
+}
+
+#[test]
+fn remove_single_node() {
+    let mut tree = Tree::new();
+    tree.insert(1);
+    assert!(tree.remove(&1));
+    assert!(!tree.remove(&1));
+    assert_eq!(tree.node_count(), 0);
+    assert!(tree.insert(1));
+    tree.check_invariants().unwrap();
+}
+
+#[test]
+fn drain_sorted() {
+    for size in 0..MAX_SIZE {
+        let keys: Vec<i32> = (0..size).collect();
+        let reversed: Vec<i32> = keys.iter().rev().copied().collect();
+        drain(&keys, &keys);
+        drain(&keys, &reversed);
+        drain(&reversed, &keys);
+        drain(&reversed, &reversed);
+    }
+}
+
+#[test]
+fn drain_random() {
+    let mut rng = StdRng::seed_from_u64(0x3a71);
+    for size in 0..MAX_SIZE {
+        for _ in 0..4 {
+            let mut keys: Vec<i32> = (0..size).collect();
+            keys.shuffle(&mut rng);
+            let mut order = keys.clone();
+            order.shuffle(&mut rng);
+            drain(&keys, &order);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use wavl::{Boxed, DotLabels, DotOptions, Tree, Unbalanced};
+
+fn dot<B: wavl::Balance>(tree: &Tree<u32, (), Boxed, B>, options: &DotOptions<u32>) -> String {
+    let mut out = vec![];
+    tree.write_dot(&mut out, options).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+// 2 is the root, removing 4 leaves it 2,2 with rank 3
+fn two_two_root() -> Tree<u32> {
+    let mut tree = Tree::new();
+    for key in 1..=4 {
+        tree.insert(key);
+    }
+    tree.remove(&4);
+    tree
+}
+
+#[test]
+fn draws_rank_differences() {
+    let tree = two_two_root();
+    let out = dot(&tree, &DotOptions::default());
+    assert!(out.starts_with("digraph Tree {\n"));
+    assert!(out.ends_with("}\n"));
+    assert!(out.contains("  n0 [label=\"2\"];\n"));
+    assert!(out.contains("  n0 -> n1 [label=\"2\"];\n"));
+    assert_eq!(out.matches("label=\"null\"").count(), 4);
+    assert!(out.contains("  {rank=same; n0;}\n"));
+    assert!(!out.contains("orange"));
+
+    let options = DotOptions {
+        labels: DotLabels::Ranks,
+        highlight_two_two: true,
+        null_leaves: false,
+        align_ranks: false,
+        ..DotOptions::default()
+    };
+    let out = dot(&tree, &options);
+    assert!(out.contains("  n0 [label=\"2\\n3\", fillcolor=orange];\n"));
+    assert!(out.contains("  n0 -> n1;\n"));
+    assert!(!out.contains("null") && !out.contains("rank=same"));
+}
+
+#[test]
+fn limits_depth_and_subtree() {
+    let mut tree: Tree<u32> = Tree::new();
+    for key in 0..15 {
+        tree.insert(key);
+    }
+
+    let options = DotOptions {
+        max_depth: Some(2),
+        ..DotOptions::default()
+    };
+    let out = dot(&tree, &options);
+    assert_eq!(out.matches("shape=plaintext").count(), 4);
+    assert_eq!(out.matches(" -> ").count(), 6);
+
+    let options = DotOptions {
+        subtree: Some(&13),
+        null_leaves: false,
+        ..DotOptions::default()
+    };
+    let out = dot(&tree, &options);
+    assert!(out.contains("  n0 [label=\"13\"];\n"));
+    assert!(out.contains("  n0 -> n2 [label=\"1\"];\n"));
+    assert_eq!(out.matches(" -> ").count(), 2);
+
+    let options = DotOptions {
+        subtree: Some(&99),
+        ..DotOptions::default()
+    };
+    assert_eq!(
+        dot(&tree, &options),
+        "digraph Tree {\n  node [shape=circle, style=filled, fillcolor=lightblue];\n}\n"
+    );
+}
+
+#[test]
+fn draws_deep_trees() {
+    const SIZE: u32 = 5000;
+    let mut tree: Tree<u32, (), Boxed, Unbalanced> = Tree::default();
+    for key in 0..SIZE {
+        tree.insert(key);
+    }
+    let out = dot(&tree, &DotOptions::default());
+    assert_eq!(out.matches(" -> ").count(), 2 * SIZE as usize);
+}
+
+#[test]
+fn escapes_labels() {
+    let mut tree: Tree<&str> = Tree::new();
+    tree.insert("say \"hi\"");
+    let mut out = vec![];
+    tree.write_dot(&mut out, &DotOptions::default()).unwrap();
+    assert!(String::from_utf8(out)
+        .unwrap()
+        .contains("[label=\"say \\\"hi\\\"\"]"));
+}
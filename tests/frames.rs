@@ -0,0 +1,123 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Boxed, Case, RedBlack, TraceFormat, Tree};
+
+#[test]
+fn labels_every_step() {
+    let mut tree: Tree<u32> = Tree::new();
+    tree.insert(1);
+    tree.insert(3);
+
+    tree.start_trace(TraceFormat::Json);
+    tree.insert(2);
+    tree.remove(&1);
+    let frames = tree.stop_trace();
+    let labels: Vec<_> = frames.iter().map(|frame| frame.label.as_str()).collect();
+    assert_eq!(
+        labels,
+        [
+            "insert 2",
+            "promote 3 to rank 2",
+            "right rotation at 3",
+            "demote 3 to rank 1",
+            "promote 2 to rank 2",
+            "left rotation at 1",
+            "demote 1 to rank 1",
+            "remove 1"
+        ]
+    );
+    assert!(frames
+        .iter()
+        .enumerate()
+        .all(|(i, frame)| frame.number == i));
+
+    // Both rotations of the double rotation, and the rank changes between
+    // them, share the step
+    let steps: Vec<_> = frames.iter().map(|frame| frame.step).collect();
+    let double = Some(Case::InsertDoubleRotate);
+    assert_eq!(
+        steps,
+        [
+            None,
+            Some(Case::InsertPromote),
+            double,
+            double,
+            double,
+            double,
+            double,
+            None
+        ]
+    );
+
+    assert_eq!(
+        frames[2].snapshot,
+        "{\"frame\": 2, \"label\": \"right rotation at 3\", \"step\": \"double rotation\", \
+         \"root\": 0, \"nodes\": [\
+         {\"key\": \"1\", \"rank\": 2, \"left\": null, \"right\": 1}, \
+         {\"key\": \"2\", \"rank\": 1, \"left\": null, \"right\": 2}, \
+         {\"key\": \"3\", \"rank\": 2, \"left\": null, \"right\": null}]}"
+    );
+    assert_eq!(
+        frames[6].snapshot,
+        "{\"frame\": 6, \"label\": \"demote 1 to rank 1\", \"step\": \"double rotation\", \
+         \"root\": 0, \"nodes\": [\
+         {\"key\": \"2\", \"rank\": 2, \"left\": 1, \"right\": 2}, \
+         {\"key\": \"1\", \"rank\": 1, \"left\": null, \"right\": null}, \
+         {\"key\": \"3\", \"rank\": 1, \"left\": null, \"right\": null}]}"
+    );
+    assert!(frames[0].snapshot.contains("\"step\": null"));
+
+    // Stopped, nothing is recorded anymore
+    tree.insert(4);
+    assert!(tree.stop_trace().is_empty());
+}
+
+#[test]
+fn captions_dot_frames() {
+    let mut tree: Tree<u32> = Tree::new();
+    tree.start_trace(TraceFormat::Dot);
+    tree.insert(1);
+    tree.insert(2);
+    let frames = tree.stop_trace();
+    assert_eq!(frames.len(), 3);
+    assert!(frames[1]
+        .snapshot
+        .starts_with("digraph Tree {\n  label=\"1: insert 2\";\n  labelloc=t;\n"));
+    assert!(frames[2].snapshot.starts_with(
+        "digraph Tree {\n  label=\"2: promote 1 to rank 2 (parent promotion)\";\n  labelloc=t;\n"
+    ));
+    assert!(frames[2].snapshot.contains("  n0 [label=\"1\"];\n"));
+}
+
+#[test]
+fn one_frame_per_operation_and_change() {
+    let mut rng = StdRng::seed_from_u64(0xf4a);
+    let mut keys: Vec<u32> = (0..300).collect();
+    keys.shuffle(&mut rng);
+
+    let mut tree: Tree<u32, (), Boxed, RedBlack> = Tree::default();
+    tree.start_trace(TraceFormat::Json);
+    for &key in &keys {
+        tree.insert(key);
+    }
+    keys.shuffle(&mut rng);
+    for &key in &keys[..200] {
+        tree.remove(&key);
+    }
+    let frames = tree.stop_trace();
+
+    let stats = tree.stats();
+    let changes = stats.rotations() + stats.promotions + stats.demotions;
+    assert_eq!(frames.len(), 500 + changes);
+    // Every frame after the operation's own belongs to a step
+    assert!(frames.iter().all(|frame| frame.step.is_some()
+        != (frame.label.starts_with("insert ") || frame.label.starts_with("remove "))));
+    assert_eq!(
+        frames
+            .iter()
+            .filter(|frame| frame.label.starts_with("remove "))
+            .count(),
+        200
+    );
+}
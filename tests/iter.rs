@@ -0,0 +1,79 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::Tree;
+
+fn shuffled_tree(size: i32) -> Tree<i32> {
+    let mut keys: Vec<i32> = (0..size).collect();
+    keys.shuffle(&mut StdRng::seed_from_u64(size as u64));
+    let mut tree = Tree::new();
+    for key in keys {
+        tree.insert(key);
+    }
+    tree
+}
+
+#[test]
+fn iter_in_order() {
+    for size in 0..100 {
+        let tree = shuffled_tree(size);
+        let keys: Vec<i32> = tree.iter().copied().collect();
+        assert_eq!(keys, (0..size).collect::<Vec<_>>());
+
+        let keys: Vec<i32> = tree.iter().rev().copied().collect();
+        assert_eq!(keys, (0..size).rev().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn iter_from_both_ends() {
+    let tree = shuffled_tree(10);
+    let mut iter = tree.iter();
+    assert_eq!(iter.len(), 10);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&9));
+    assert_eq!(iter.len(), 8);
+
+    let rest: Vec<i32> = iter.by_ref().copied().collect();
+    assert_eq!(rest, (1..9).collect::<Vec<_>>());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn for_loop_over_reference() {
+    let tree = shuffled_tree(50);
+    let mut expected = 0;
+    for key in &tree {
+        assert_eq!(*key, expected);
+        expected += 1;
+    }
+    assert_eq!(expected, 50);
+}
+
+#[test]
+fn into_iter_consumes_nodes() {
+    for size in 0..100 {
+        let keys: Vec<i32> = shuffled_tree(size).into_iter().collect();
+        assert_eq!(keys, (0..size).collect::<Vec<_>>());
+    }
+
+    let mut iter = shuffled_tree(10).into_iter();
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.len(), 8);
+    assert_eq!(
+        iter.rev().collect::<Vec<_>>(),
+        (1..9).rev().collect::<Vec<_>>()
+    );
+
+    // Dropping a partially consumed iterator frees the remaining nodes
+    let mut iter = shuffled_tree(10).into_iter();
+    iter.next();
+
+    let strings: Tree<String> = ["b", "a", "c"].iter().fold(Tree::new(), |mut tree, s| {
+        tree.insert(s.to_string());
+        tree
+    });
+    assert_eq!(strings.into_iter().collect::<Vec<_>>(), ["a", "b", "c"]);
+}
@@ -0,0 +1,118 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Arena, Avl, Balance, Boxed, RedBlack, Storage, Summary, Tree, Unbalanced, Wavl};
+
+// Sum of the keys below a node
+struct Sum(u64);
+
+impl Summary<u32> for Sum {
+    fn empty() -> Self {
+        Sum(0)
+    }
+
+    fn combine(left: &Self, key: &u32, right: &Self) -> Self {
+        Sum(left.0 + u64::from(*key) + right.0)
+    }
+}
+
+fn shuffled<A: Storage, B: Balance>(keys: std::ops::Range<u32>, seed: u64) -> Tree<u32, Sum, A, B> {
+    let mut keys: Vec<u32> = keys.collect();
+    keys.shuffle(&mut StdRng::seed_from_u64(seed));
+    let mut tree = Tree::default();
+    for key in keys {
+        tree.insert(key);
+    }
+    tree
+}
+
+fn assert_holds<A: Storage, B: Balance>(tree: &Tree<u32, Sum, A, B>, keys: std::ops::Range<u32>) {
+    tree.check_invariants().unwrap();
+    assert_eq!(tree.node_count(), keys.len());
+    assert!(tree.iter().copied().eq(keys.clone()));
+    assert_eq!(tree.fold_range(..).0, keys.map(u64::from).sum::<u64>());
+}
+
+// Splits at every point and puts the halves back together both ways
+fn split_and_append<A: Storage, B: Balance>() {
+    const SIZE: u32 = 300;
+    for at in (0..=SIZE + 1).step_by(7) {
+        let mut left = shuffled::<A, B>(0..SIZE, u64::from(at));
+        let mut right = left.split_off(&at);
+        let at = at.min(SIZE);
+        assert_holds(&left, 0..at);
+        assert_holds(&right, at..SIZE);
+
+        left.append(&mut right);
+        assert_holds(&left, 0..SIZE);
+        assert_holds(&right, 0..0);
+
+        let mut right = left.split_off(&at);
+        right.append(&mut left);
+        assert_holds(&right, 0..SIZE);
+        assert_holds(&left, 0..0);
+    }
+}
+
+#[test]
+fn splits_and_appends() {
+    split_and_append::<Boxed, Wavl>();
+    split_and_append::<Boxed, Avl>();
+    split_and_append::<Boxed, RedBlack>();
+    split_and_append::<Boxed, Unbalanced>();
+    split_and_append::<Arena, Wavl>();
+    split_and_append::<Arena, RedBlack>();
+}
+
+// Joins small and large trees on either side of the middle key
+fn joins_any_height<A: Storage, B: Balance>() {
+    for (small, large) in [(0, 0), (0, 1000), (3, 1000), (500, 600), (1000, 1)] {
+        let left = shuffled::<A, B>(0..small, 1);
+        let right = shuffled::<A, B>(small + 1..small + 1 + large, 2);
+        let tree = Tree::join(left, small, right);
+        assert_holds(&tree, 0..small + 1 + large);
+
+        let left = shuffled::<A, B>(0..large, 3);
+        let right = shuffled::<A, B>(large + 1..large + 1 + small, 4);
+        let tree = Tree::join(left, large, right);
+        assert_holds(&tree, 0..large + 1 + small);
+    }
+}
+
+#[test]
+fn joins_trees_of_any_height() {
+    joins_any_height::<Boxed, Wavl>();
+    joins_any_height::<Boxed, Avl>();
+    joins_any_height::<Boxed, RedBlack>();
+    joins_any_height::<Arena, Wavl>();
+    joins_any_height::<Arena, Avl>();
+    joins_any_height::<Arena, RedBlack>();
+}
+
+#[test]
+fn joins_with_few_rotations() {
+    let mut tree: Tree<u32> = (0..100_000).collect();
+    let mut right = tree.split_off(&31_415);
+    tree.reset_stats();
+    tree.append(&mut right);
+    let stats = tree.stats();
+    assert!(stats.rotations() <= 2, "{:?}", stats);
+    assert!(stats.promotions + stats.demotions < 40, "{:?}", stats);
+}
+
+#[test]
+fn appends_overlapping_trees_key_by_key() {
+    let mut tree = shuffled::<Boxed, Wavl>(0..200, 5);
+    let mut other = shuffled::<Boxed, Wavl>(100..300, 6);
+    tree.append(&mut other);
+    assert_holds(&tree, 0..300);
+    assert_holds(&other, 0..0);
+}
+
+#[test]
+#[should_panic(expected = "join needs")]
+fn rejects_joins_out_of_order() {
+    let left: Tree<u32> = (0..10).collect();
+    let right: Tree<u32> = (20..30).collect();
+    Tree::join(left, 25, right);
+}
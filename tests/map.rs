@@ -0,0 +1,80 @@
+use wavl::{Entry, WavlMap};
+
+#[test]
+fn insert_returns_the_old_value() {
+    let mut map = WavlMap::new();
+    assert!(map.is_empty());
+    assert_eq!(map.insert(1, "one"), None);
+    assert_eq!(map.insert(2, "two"), None);
+    assert_eq!(map.insert(1, "uno"), Some("one"));
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(&1), Some(&"uno"));
+}
+
+#[test]
+fn looks_up_through_a_shared_borrow() {
+    let mut map = WavlMap::new();
+    for key in 0..10 {
+        map.insert(key, key * 10);
+    }
+
+    let shared = &map;
+    let (three, seven) = (shared.get(&3), shared.get(&7));
+    assert_eq!((three, seven), (Some(&30), Some(&70)));
+    assert!(shared.contains_key(&9));
+    assert!(!shared.contains_key(&10));
+    assert_eq!(shared.get(&10), None);
+}
+
+#[test]
+fn get_mut_changes_the_value() {
+    let mut map = WavlMap::new();
+    map.insert("a", 1);
+    *map.get_mut("a").unwrap() += 41;
+    assert_eq!(map.get("a"), Some(&42));
+    assert_eq!(map.get_mut("b"), None);
+}
+
+#[test]
+fn entries_insert_and_modify() {
+    let mut map: WavlMap<&str, u32> = WavlMap::new();
+    for word in ["b", "a", "b", "c", "b"] {
+        *map.entry(word).or_insert(0) += 1;
+    }
+    assert_eq!(map.get("b"), Some(&3));
+    assert_eq!(map.len(), 3);
+
+    map.entry("a").and_modify(|count| *count *= 10).or_default();
+    map.entry("d").and_modify(|count| *count *= 10).or_default();
+    assert_eq!(map.get("a"), Some(&10));
+    assert_eq!(map.get("d"), Some(&0));
+
+    match map.entry("c") {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.key(), &"c");
+            assert_eq!(entry.insert(7), 1);
+            assert_eq!(entry.remove(), 7);
+        }
+        Entry::Vacant(_) => panic!("c is in the map"),
+    }
+    match map.entry("e") {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), "e"),
+        Entry::Occupied(_) => panic!("e is not in the map"),
+    }
+    assert!(!map.contains_key("c") && !map.contains_key("e"));
+    assert_eq!(map.len(), 3);
+}
+
+#[test]
+fn remove_hands_back_the_value() {
+    let mut map = WavlMap::new();
+    for key in 0..100 {
+        map.insert(key, key.to_string());
+    }
+    for key in (0..100).step_by(2) {
+        assert_eq!(map.remove(&key), Some(key.to_string()));
+    }
+    assert_eq!(map.remove(&0), None);
+    assert_eq!(map.len(), 50);
+    assert!((0..100).all(|key| map.contains_key(&key) == (key % 2 == 1)));
+}
@@ -0,0 +1,169 @@
+// Small cases that exercise every pointer update of the tree, sized to run
+// under Miri as well:
+//
+//     cargo +nightly miri test --test miri
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use wavl::{Arena, Tree, WavlMap};
+
+const SIZE: u32 = 40;
+
+// Counts its drops, so a leaked or doubly freed key shows up without Miri too
+struct Tracked {
+    key: u32,
+    drops: Rc<Cell<usize>>,
+}
+
+impl PartialEq for Tracked {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for Tracked {}
+
+impl PartialOrd for Tracked {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Tracked {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+// A fixed pseudo random permutation of 0..n
+fn shuffled(n: u32) -> Vec<u32> {
+    let mut keys: Vec<u32> = (0..n).collect();
+    let mut state = 0x2545_f491_u32;
+    for i in (1..keys.len()).rev() {
+        state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        keys.swap(i, (state >> 8) as usize % (i + 1));
+    }
+    keys
+}
+
+#[test]
+fn insert_and_remove_rotate() {
+    for keys in [
+        (0..SIZE).collect(),
+        (0..SIZE).rev().collect(),
+        shuffled(SIZE),
+    ] {
+        let mut tree = Tree::new();
+        for &key in &keys {
+            assert!(tree.insert(key.to_string()));
+            tree.check_invariants().unwrap();
+        }
+        assert!(tree.stats().rotations() > 0);
+
+        for key in shuffled(SIZE) {
+            assert!(tree.remove(key.to_string().as_str()));
+            tree.check_invariants().unwrap();
+        }
+        assert_eq!(tree.node_count(), 0);
+    }
+}
+
+#[test]
+fn drop_frees_every_key() {
+    let drops = Rc::new(Cell::new(0));
+    let mut tree = Tree::new();
+    for key in shuffled(SIZE) {
+        tree.insert(Tracked {
+            key,
+            drops: drops.clone(),
+        });
+    }
+
+    // A rejected duplicate is dropped right away
+    tree.insert(Tracked {
+        key: 0,
+        drops: drops.clone(),
+    });
+    assert_eq!(drops.get(), 1);
+
+    drop(tree);
+    assert_eq!(drops.get(), SIZE as usize + 1);
+}
+
+#[test]
+fn arena_drop_frees_every_key() {
+    let drops = Rc::new(Cell::new(0));
+    let mut tree: Tree<Tracked, (), Arena> = Tree::default();
+    for key in shuffled(SIZE) {
+        tree.insert(Tracked {
+            key,
+            drops: drops.clone(),
+        });
+    }
+    for key in 0..SIZE / 2 {
+        tree.remove(&Tracked {
+            key,
+            drops: drops.clone(),
+        });
+    }
+    // Each removal drops the stored key and the one looked up with
+    assert_eq!(drops.get(), SIZE as usize);
+
+    drop(tree);
+    assert_eq!(drops.get(), SIZE as usize * 3 / 2);
+}
+
+#[test]
+fn into_iter_frees_the_rest() {
+    let drops = Rc::new(Cell::new(0));
+    let mut tree = Tree::new();
+    for key in shuffled(SIZE) {
+        tree.insert(Tracked {
+            key,
+            drops: drops.clone(),
+        });
+    }
+
+    let mut iter = tree.into_iter();
+    assert_eq!(iter.next().map(|t| t.key), Some(0));
+    assert_eq!(iter.next_back().map(|t| t.key), Some(SIZE - 1));
+    assert_eq!(drops.get(), 2);
+
+    drop(iter);
+    assert_eq!(drops.get(), SIZE as usize);
+}
+
+#[test]
+fn borrowing_iterators() {
+    let mut tree = Tree::new();
+    for key in shuffled(SIZE) {
+        tree.insert(key);
+    }
+
+    assert!(tree.iter().copied().eq(0..SIZE));
+    assert!(tree.iter().rev().copied().eq((0..SIZE).rev()));
+    assert!(tree.range(10..20).copied().eq(10..20));
+    assert_eq!(tree.floor(&(SIZE + 5)), Some(&(SIZE - 1)));
+}
+
+#[test]
+fn map_entries() {
+    let mut map = WavlMap::new();
+    for key in shuffled(SIZE) {
+        *map.entry(key % 10).or_insert(0) += 1;
+    }
+    assert_eq!(map.len(), 10);
+
+    for key in 0..10 {
+        assert_eq!(map.get(&key), Some(&(SIZE / 10)));
+        assert_eq!(map.remove(&key), Some(SIZE / 10));
+    }
+    assert!(map.is_empty());
+}
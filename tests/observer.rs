@@ -0,0 +1,165 @@
+use std::cell::Cell;
+use std::sync::mpsc::{channel, Receiver, Sender};
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Case, Observer, RedBlack, Side, Tree};
+
+// Sends every event down a channel
+struct Log(Sender<String>);
+
+impl Log {
+    fn new() -> (Log, Receiver<String>) {
+        let (sender, receiver) = channel();
+        (Log(sender), receiver)
+    }
+
+    fn send(&self, event: String) {
+        self.0.send(event).unwrap();
+    }
+}
+
+impl Observer<u32> for Log {
+    fn rotate(&mut self, side: Side, node: &u32, child: &u32) {
+        self.send(format!("rotate {:?} {} {}", side, node, child));
+    }
+
+    fn promote(&mut self, key: &u32, rank: i32) {
+        self.send(format!("promote {} {}", key, rank));
+    }
+
+    fn demote(&mut self, key: &u32, rank: i32) {
+        self.send(format!("demote {} {}", key, rank));
+    }
+
+    fn alloc(&mut self, key: &u32) {
+        self.send(format!("alloc {}", key));
+    }
+
+    fn free(&mut self, key: &u32) {
+        self.send(format!("free {}", key));
+    }
+
+    fn rebalance(&mut self, case: Case) {
+        self.send(format!("{:?}", case));
+    }
+}
+
+#[test]
+fn reports_events_in_order() {
+    let (log, events) = Log::new();
+    let mut tree: Tree<u32> = Tree::new();
+    tree.insert(1);
+    tree.set_observer(log);
+
+    tree.insert(2);
+    tree.insert(3);
+    assert_eq!(
+        events.try_iter().collect::<Vec<_>>(),
+        [
+            "alloc 2",
+            "InsertPromote",
+            "promote 1 2",
+            "alloc 3",
+            "InsertPromote",
+            "promote 2 2",
+            "InsertRotate",
+            "rotate Left 1 2",
+            "demote 1 1",
+        ]
+    );
+
+    tree.remove(&1);
+    tree.remove(&3);
+    assert_eq!(
+        events.try_iter().collect::<Vec<_>>(),
+        ["free 1", "DeleteLeaf", "demote 2 1", "free 3"]
+    );
+
+    assert!(tree.take_observer().is_some());
+    tree.insert(4);
+    assert!(events.try_recv().is_err());
+}
+
+// Counts the events that `Stats` counts as well and sends the counts when it
+// is dropped. The cells make it `Send` but not `Sync`, which is all an
+// observer needs.
+struct Counter([Cell<usize>; 4], Sender<[usize; 4]>);
+
+impl Counter {
+    fn new() -> (Counter, Receiver<[usize; 4]>) {
+        let (sender, receiver) = channel();
+        (Counter(Default::default(), sender), receiver)
+    }
+
+    fn count(&self, event: usize) {
+        self.0[event].set(self.0[event].get() + 1);
+    }
+}
+
+impl<K> Observer<K> for Counter {
+    fn rotate(&mut self, _: Side, _: &K, _: &K) {
+        self.count(0);
+    }
+
+    fn promote(&mut self, _: &K, _: i32) {
+        self.count(1);
+    }
+
+    fn demote(&mut self, _: &K, _: i32) {
+        self.count(2);
+    }
+
+    fn rebalance(&mut self, _: Case) {
+        self.count(3);
+    }
+}
+
+impl Drop for Counter {
+    fn drop(&mut self) {
+        let _ = self.1.send(self.0.each_ref().map(Cell::get));
+    }
+}
+
+#[test]
+fn events_match_stats() {
+    let mut rng = StdRng::seed_from_u64(0x0b5);
+    let mut keys: Vec<u32> = (0..1000).collect();
+    keys.shuffle(&mut rng);
+
+    let (counter, counts) = Counter::new();
+    let mut tree: Tree<u32, (), wavl::Boxed, RedBlack> = Tree::default();
+    tree.set_observer(counter);
+    for &key in &keys {
+        tree.insert(key);
+    }
+    keys.shuffle(&mut rng);
+    for &key in &keys[..600] {
+        tree.remove(&key);
+    }
+
+    // A tree with an observer that is not `Sync` can still be shared, and
+    // sent along with its observer
+    std::thread::scope(|scope| {
+        let tree = &tree;
+        scope.spawn(move || assert_eq!(tree.node_count(), 400));
+    });
+    let mut tree = std::thread::spawn(move || tree).join().unwrap();
+
+    drop(tree.take_observer());
+    let stats = tree.stats();
+    let steps = wavl::Case::ALL.iter().map(|&case| stats.case(case)).sum();
+    assert_eq!(
+        counts.recv().unwrap(),
+        [stats.rotations(), stats.promotions, stats.demotions, steps]
+    );
+
+    // Keys only need to live as long as the tree
+    let words = String::from("observed keys borrow from here");
+    let mut borrowed: Tree<&str> = Tree::new();
+    borrowed.set_observer(Counter::new().0);
+    for word in words.split(' ') {
+        borrowed.insert(word);
+    }
+}
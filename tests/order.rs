@@ -0,0 +1,52 @@
+#![cfg(feature = "order-statistics")]
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use wavl::Tree;
+
+#[test]
+fn select_and_rank_of_follow_updates() {
+    let mut rng = StdRng::seed_from_u64(0x5e1ec7);
+    let mut tree = Tree::new();
+    let mut keys: Vec<i32> = vec![];
+
+    for _ in 0..2000 {
+        let key = rng.gen_range(0..500) * 2;
+        if rng.gen_bool(0.6) {
+            if tree.insert(key) {
+                let index = keys.binary_search(&key).unwrap_err();
+                keys.insert(index, key);
+            }
+        } else if tree.remove(&key) {
+            keys.retain(|k| *k != key);
+        }
+        tree.check_invariants().unwrap();
+
+        let i = rng.gen_range(0..=keys.len());
+        assert_eq!(tree.select(i), keys.get(i));
+
+        // Odd probes are never in the tree
+        let probe = rng.gen_range(-1..1001);
+        let below = keys.iter().filter(|k| **k < probe).count();
+        assert_eq!(tree.rank_of(&probe), below);
+    }
+}
+
+#[test]
+fn select_inverts_rank_of() {
+    let mut keys: Vec<u64> = (0..300).map(|k| k * 3).collect();
+    keys.shuffle(&mut StdRng::seed_from_u64(3));
+    let mut tree = Tree::new();
+    for key in &keys {
+        tree.insert(*key);
+    }
+
+    for (i, key) in tree.iter().enumerate() {
+        assert_eq!(tree.rank_of(key), i);
+        assert_eq!(tree.select(i), Some(key));
+    }
+    assert_eq!(tree.select(300), None);
+    assert_eq!(tree.rank_of(&10_000), 300);
+    assert_eq!(Tree::<u64>::new().select(0), None);
+}
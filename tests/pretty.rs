@@ -0,0 +1,66 @@
+use wavl::{Boxed, PrettyOptions, Tree, Unbalanced};
+
+// 0..10 with 2 removed leaves 1 a 2,1 node
+fn tree() -> Tree<u32> {
+    let mut tree = Tree::new();
+    for key in 0..10 {
+        tree.insert(key);
+    }
+    tree.remove(&2);
+    tree
+}
+
+#[test]
+fn draws_ranks_and_differences() {
+    assert_eq!(
+        tree().pretty(PrettyOptions::default()).to_string(),
+        "3 (rank 4)\n\
+         ├── L 1 (rank 2, diff 2)\n\
+         │   └── L 0 (rank 1, diff 1)\n\
+         └── R 7 (rank 3, diff 1)\n    \
+             ├── L 5 (rank 2, diff 1)\n    \
+             │   ├── L 4 (rank 1, diff 1)\n    \
+             │   └── R 6 (rank 1, diff 1)\n    \
+             └── R 8 (rank 2, diff 1)\n        \
+                 └── R 9 (rank 1, diff 1)\n"
+    );
+}
+
+#[test]
+fn limits_depth_in_ascii() {
+    let options = PrettyOptions {
+        max_depth: Some(2),
+        ascii: true,
+    };
+    assert_eq!(
+        tree().pretty(options).to_string(),
+        "3 (rank 4)\n\
+         +-- L 1 (rank 2, diff 2)\n\
+         |   `-- ...\n\
+         `-- R 7 (rank 3, diff 1)\n    \
+             `-- ...\n"
+    );
+}
+
+#[test]
+fn debug_draws_keys_in_debug_form() {
+    let mut tree: Tree<&str> = Tree::new();
+    assert_eq!(format!("{:?}", tree), "(empty)\n");
+    tree.insert("a");
+    tree.insert("b");
+    assert_eq!(
+        format!("{:?}", tree),
+        "\"a\" (rank 2)\n└── R \"b\" (rank 1, diff 1)\n"
+    );
+}
+
+#[test]
+fn draws_deep_trees() {
+    const SIZE: u32 = 5000;
+    let mut tree: Tree<u32, (), Boxed, Unbalanced> = Tree::default();
+    for key in 0..SIZE {
+        tree.insert(key);
+    }
+    let out = tree.pretty(PrettyOptions::default()).to_string();
+    assert_eq!(out.lines().count(), SIZE as usize);
+}
@@ -0,0 +1,89 @@
+use std::ops::Bound::{self, Excluded, Included, Unbounded};
+
+use wavl::Tree;
+
+// Even keys 0, 2, .., 98 so that bounds can fall between keys
+fn even_tree() -> Tree<i32> {
+    let mut tree = Tree::new();
+    for key in (0..100).step_by(2) {
+        tree.insert(key);
+    }
+    tree
+}
+
+fn expected(lo: Bound<i32>, hi: Bound<i32>) -> Vec<i32> {
+    (0..100)
+        .step_by(2)
+        .filter(|key| match lo {
+            Included(lo) => *key >= lo,
+            Excluded(lo) => *key > lo,
+            Unbounded => true,
+        })
+        .filter(|key| match hi {
+            Included(hi) => *key <= hi,
+            Excluded(hi) => *key < hi,
+            Unbounded => true,
+        })
+        .collect()
+}
+
+#[test]
+fn all_bound_kinds() {
+    let tree = even_tree();
+    let bounds = |key| [Included(key), Excluded(key), Unbounded];
+    for lo in -2..102 {
+        for hi in -2..102 {
+            for lo in bounds(lo) {
+                for hi in bounds(hi) {
+                    let keys: Vec<i32> = tree.range((lo, hi)).copied().collect();
+                    assert_eq!(keys, expected(lo, hi), "{:?}..{:?}", lo, hi);
+
+                    let mut keys: Vec<i32> = tree.range((lo, hi)).rev().copied().collect();
+                    keys.reverse();
+                    assert_eq!(keys, expected(lo, hi), "rev {:?}..{:?}", lo, hi);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn range_syntax() {
+    let tree = even_tree();
+    assert_eq!(
+        tree.range(10..16).copied().collect::<Vec<_>>(),
+        [10, 12, 14]
+    );
+    assert_eq!(
+        tree.range(10..=16).copied().collect::<Vec<_>>(),
+        [10, 12, 14, 16]
+    );
+    assert_eq!(tree.range(..5).copied().collect::<Vec<_>>(), [0, 2, 4]);
+    assert_eq!(tree.range(95..).copied().collect::<Vec<_>>(), [96, 98]);
+    assert_eq!(tree.range(..).count(), 50);
+    assert_eq!(tree.range((Included(20), Excluded(10))).next(), None);
+    assert_eq!(Tree::<i32>::new().range(..).next(), None);
+
+    let mut strings = Tree::new();
+    for s in ["apple", "banana", "cherry"] {
+        strings.insert(s.to_string());
+    }
+    let fruits: Vec<&String> = strings
+        .range::<str, _>((Included("b"), Unbounded))
+        .collect();
+    assert_eq!(fruits, ["banana", "cherry"]);
+}
+
+#[test]
+fn meet_in_the_middle() {
+    let tree = even_tree();
+    let mut range = tree.range(10..=20);
+    assert_eq!(range.next(), Some(&10));
+    assert_eq!(range.next_back(), Some(&20));
+    assert_eq!(range.next_back(), Some(&18));
+    assert_eq!(range.next(), Some(&12));
+    assert_eq!(range.next(), Some(&14));
+    assert_eq!(range.next_back(), Some(&16));
+    assert_eq!(range.next(), None);
+    assert_eq!(range.next_back(), None);
+}
@@ -0,0 +1,146 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use wavl::{Avl, Balance, Boxed, Case, RedBlack, Stats, Tree, Unbalanced, Wavl};
+
+#[test]
+fn counts_the_steps_of_a_rotation() {
+    let mut tree: Tree<u32> = Tree::new();
+    tree.insert(1);
+    tree.insert(2);
+    assert_eq!(tree.stats().case(Case::InsertPromote), 1);
+    assert_eq!(tree.stats().promotions, 1);
+
+    tree.reset_stats();
+    tree.insert(3);
+    let stats = tree.stats();
+    // 3 makes 2 a 0,1 node, promoting 2 leaves 1 unbalanced
+    assert_eq!(stats.case(Case::InsertPromote), 1);
+    assert_eq!(stats.case(Case::InsertRotate), 1);
+    assert_eq!((stats.single_rotations, stats.double_rotations), (1, 0));
+    assert_eq!((stats.promotions, stats.demotions), (1, 1));
+    assert_eq!(stats.accessed_nodes, 2);
+
+    tree.reset_stats();
+    assert_eq!(*tree.stats(), Stats::default());
+}
+
+// The steps taken, as the non-zero counts per case, and the promotions and
+// demotions, first for inserting `inserts` and then for removing `removes`
+type Counts = (Vec<(Case, usize)>, usize, usize);
+
+fn counts<B: Balance>(inserts: &[u32], removes: &[u32]) -> [Counts; 2] {
+    let mut tree: Tree<u32, (), Boxed, B> = Tree::default();
+    for &key in inserts {
+        tree.insert(key);
+    }
+    let inserted = take_counts(&mut tree);
+    for key in removes {
+        tree.remove(key);
+    }
+    [inserted, take_counts(&mut tree)]
+}
+
+fn take_counts<B: Balance>(tree: &mut Tree<u32, (), Boxed, B>) -> Counts {
+    tree.check_invariants().unwrap();
+    let stats = tree.stats();
+    let cases = Case::ALL
+        .into_iter()
+        .map(|case| (case, stats.case(case)))
+        .filter(|&(_, count)| count > 0)
+        .collect();
+    let counts = (cases, stats.promotions, stats.demotions);
+    tree.reset_stats();
+    counts
+}
+
+#[test]
+fn counts_each_case_of_ascending_insertions() {
+    let keys: Vec<u32> = (1..=7).collect();
+    // Every key after 1 promotes its parent, 4 and 6 one more node, and
+    // every key from 3 on but 4 ends in a rotation
+    let wavl = (
+        vec![(Case::InsertPromote, 8), (Case::InsertRotate, 4)],
+        8,
+        4,
+    );
+    assert_eq!(counts::<Wavl>(&keys, &[]), [wavl.clone(), (vec![], 0, 0)]);
+    assert_eq!(counts::<Avl>(&keys, &[]), [wavl, (vec![], 0, 0)]);
+    // 4 and 6 recolor their grandparent, 3, 5 and 7 rotate
+    let red_black = vec![(Case::InsertPromoteGrandparent, 2), (Case::InsertRotate, 3)];
+    assert_eq!(
+        counts::<RedBlack>(&keys, &[]),
+        [(red_black, 2, 0), (vec![], 0, 0)]
+    );
+    assert_eq!(
+        counts::<Unbalanced>(&keys, &[]),
+        [(vec![], 0, 0), (vec![], 0, 0)]
+    );
+}
+
+#[test]
+fn counts_a_double_rotation() {
+    let keys = [3, 1, 2];
+    let wavl = (
+        vec![(Case::InsertPromote, 2), (Case::InsertDoubleRotate, 1)],
+        3,
+        2,
+    );
+    assert_eq!(counts::<Wavl>(&keys, &[])[0], wavl);
+    assert_eq!(counts::<Avl>(&keys, &[])[0], wavl);
+    let red_black = (vec![(Case::InsertDoubleRotate, 1)], 0, 0);
+    assert_eq!(counts::<RedBlack>(&keys, &[])[0], red_black);
+}
+
+#[test]
+fn counts_each_case_of_removals() {
+    let keys: Vec<u32> = (1..=15).collect();
+    let removes = [15, 13, 11, 9, 14, 10, 12, 8, 1, 3, 2];
+    let inserted = (
+        vec![(Case::InsertPromote, 22), (Case::InsertRotate, 11)],
+        22,
+        11,
+    );
+
+    let wavl = vec![
+        (Case::DeleteLeaf, 4),
+        (Case::DeleteDemote, 1),
+        (Case::DeleteDoubleDemote, 1),
+        (Case::DeleteRotate, 3),
+    ];
+    assert_eq!(
+        counts::<Wavl>(&keys, &removes),
+        [inserted.clone(), (wavl, 3, 10)]
+    );
+    let avl = vec![(Case::DeleteDemote, 7), (Case::DeleteRotate, 3)];
+    assert_eq!(counts::<Avl>(&keys, &removes), [inserted, (avl, 3, 10)]);
+    let red_black = vec![
+        (Case::DeleteDemote, 7),
+        (Case::DeleteRotateSibling, 2),
+        (Case::DeleteRotate, 1),
+    ];
+    assert_eq!(counts::<RedBlack>(&keys, &removes)[1], (red_black, 1, 8));
+    assert_eq!(counts::<Unbalanced>(&keys, &removes)[1], (vec![], 0, 0));
+}
+
+#[test]
+fn wavl_rotates_at_most_once_per_operation() {
+    let mut rng = StdRng::seed_from_u64(0x57a7);
+    let mut keys: Vec<u32> = (0..2000).collect();
+    keys.shuffle(&mut rng);
+
+    let mut tree: Tree<u32> = Tree::new();
+    for &key in &keys {
+        tree.reset_stats();
+        tree.insert(key);
+        assert!(tree.stats().single_rotations + tree.stats().double_rotations <= 1);
+    }
+    keys.shuffle(&mut rng);
+    for &key in &keys {
+        tree.reset_stats();
+        tree.remove(&key);
+        let stats = tree.stats();
+        assert!(stats.single_rotations + stats.double_rotations <= 1);
+        assert_eq!(stats.case(Case::InsertPromote), 0);
+    }
+}
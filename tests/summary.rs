@@ -0,0 +1,103 @@
+use std::ops::Bound::{Excluded, Included, Unbounded};
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use wavl::{Summary, Tree};
+
+#[derive(Debug, PartialEq)]
+struct Stats {
+    count: usize,
+    sum: i64,
+    min: Option<i64>,
+    max: Option<i64>,
+}
+
+impl Summary<i64> for Stats {
+    fn empty() -> Self {
+        Stats {
+            count: 0,
+            sum: 0,
+            min: None,
+            max: None,
+        }
+    }
+
+    fn combine(left: &Self, key: &i64, right: &Self) -> Self {
+        Stats {
+            count: left.count + 1 + right.count,
+            sum: left.sum + key + right.sum,
+            min: Some(left.min.unwrap_or(*key)),
+            max: Some(right.max.unwrap_or(*key)),
+        }
+    }
+}
+
+fn naive(keys: &[i64], lo: i64, hi: i64) -> Stats {
+    let keys: Vec<i64> = keys
+        .iter()
+        .copied()
+        .filter(|k| lo <= *k && *k < hi)
+        .collect();
+    Stats {
+        count: keys.len(),
+        sum: keys.iter().sum(),
+        min: keys.iter().min().copied(),
+        max: keys.iter().max().copied(),
+    }
+}
+
+#[test]
+fn fold_range_through_updates() {
+    let mut rng = StdRng::seed_from_u64(0x50);
+    let mut tree: Tree<i64, Stats> = Tree::default();
+    let mut keys = vec![];
+
+    for _ in 0..3000 {
+        let key = rng.gen_range(-500..500);
+        if rng.gen_bool(0.6) {
+            if tree.insert(key) {
+                keys.push(key);
+            }
+        } else if tree.remove(&key) {
+            keys.retain(|k| *k != key);
+        }
+
+        let lo = rng.gen_range(-550..550);
+        let hi = rng.gen_range(lo..560);
+        assert_eq!(tree.fold_range(lo..hi), naive(&keys, lo, hi));
+    }
+
+    assert_eq!(tree.fold_range(..), naive(&keys, i64::MIN, i64::MAX));
+    assert_eq!(tree.fold_range((Excluded(10), Included(5))), Stats::empty());
+}
+
+// Intervals keyed by (start, end), summarised by the largest end
+struct MaxEnd(u32);
+
+impl Summary<(u32, u32)> for MaxEnd {
+    fn empty() -> Self {
+        MaxEnd(0)
+    }
+
+    fn combine(left: &Self, key: &(u32, u32), right: &Self) -> Self {
+        MaxEnd(left.0.max(key.1).max(right.0))
+    }
+}
+
+#[test]
+fn interval_max_end() {
+    let mut tree: Tree<(u32, u32), MaxEnd> = Tree::default();
+    for interval in [(1, 4), (2, 9), (5, 6), (7, 8), (10, 12)] {
+        tree.insert(interval);
+    }
+
+    // Some interval starting at or before 3 reaches 9
+    assert_eq!(tree.fold_range(..=(3, u32::MAX)).0, 9);
+    assert_eq!(tree.fold_range((5, 0)..(10, 0)).0, 8);
+
+    tree.remove(&(2, 9));
+    assert_eq!(tree.fold_range(..=(3, u32::MAX)).0, 4);
+    assert_eq!(tree.fold_range((Unbounded, Included((7, 8)))).0, 8);
+    assert_eq!(tree.fold_range(..).0, 12);
+    assert_eq!(Tree::<(u32, u32), MaxEnd>::default().fold_range(..).0, 0);
+}
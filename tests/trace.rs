@@ -0,0 +1,44 @@
+// Replays workloads recorded by the bench example, see `--record` there, and
+// compares every step with a BTreeSet. The traces are read with the bench's
+// own reader.
+
+use std::collections::BTreeSet;
+
+use wavl::{Avl, Balance, Boxed, RedBlack, Tree, Unbalanced, Wavl};
+
+#[allow(dead_code)]
+#[path = "../examples/bench/trace.rs"]
+mod trace;
+
+use trace::Op;
+
+fn replay<B: Balance>(keys: &[i32], ops: &[Op]) {
+    let mut tree: Tree<i32, (), Boxed, B> = Tree::default();
+    let mut expected = BTreeSet::new();
+    for &key in keys {
+        assert_eq!(tree.insert(key), expected.insert(key));
+    }
+    tree.check_invariants().unwrap();
+
+    for op in ops {
+        match *op {
+            Op::Insert(key) => assert_eq!(tree.insert(key), expected.insert(key)),
+            Op::Find(key) => assert_eq!(tree.find(&key), expected.contains(&key)),
+            Op::Remove(key) => assert_eq!(tree.remove(&key), expected.remove(&key)),
+        }
+        tree.check_invariants().unwrap();
+    }
+    assert!(tree.iter().eq(expected.iter()));
+}
+
+#[test]
+fn clustered_trace() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/traces/clustered.trace");
+    let (keys, ops) = trace::read(path).unwrap();
+    assert_eq!((keys.len(), ops.len()), (200, 1500));
+
+    replay::<Wavl>(&keys, &ops);
+    replay::<Avl>(&keys, &ops);
+    replay::<RedBlack>(&keys, &ops);
+    replay::<Unbalanced>(&keys, &ops);
+}